@@ -2,6 +2,7 @@ use std::time::Duration;
 
 use bevy::prelude::*;
 use bevy_mod_async::{time::TimingTaskExt, AsyncTasksPlugin, SpawnCommandExt};
+use futures::StreamExt;
 
 fn main() {
     App::new()
@@ -16,10 +17,15 @@ fn main() {
 
 fn setup(mut commands: Commands) {
     commands.spawn_task(|cx| async move {
+        cx.sleep(Duration::from_secs(1)).await;
+        println!("Starting counter");
+        // Sleeping in a loop would drift by up to a frame each iteration, since each sleep only
+        // starts once the previous one has been processed. `interval` keeps to its original
+        // schedule instead
+        let mut ticks = cx.interval(Duration::from_secs(1));
         let mut counter = 0;
-        loop {
-            cx.sleep(Duration::from_secs(1)).await;
-            println!("Counter: {counter}");
+        while let Some(tick) = ticks.next().await {
+            println!("Counter: {counter} (missed {} ticks)", tick.missed);
             counter += 1;
         }
     });
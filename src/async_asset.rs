@@ -1,25 +1,103 @@
 use std::{
-    collections::HashMap,
-    pin::Pin,
+    any::TypeId,
+    collections::{hash_map::Entry, HashMap, HashSet},
+    fmt,
+    future::Future,
+    pin::{pin, Pin},
+    sync::Arc,
     task::{Context, Poll},
 };
 
-use bevy_asset::{AssetLoadError, AssetServer, RecursiveDependencyLoadState, UntypedAssetId};
+use bevy_app::{App, Last};
+use bevy_asset::{
+    io::{AssetWriterError, MissingAssetSourceError, MissingAssetWriterError},
+    Asset, AssetEvent, AssetId, AssetLoadError, AssetPath, AssetServer, DependencyLoadState,
+    Handle, LoadState, RecursiveDependencyLoadState, UntypedAssetId, UntypedAssetLoadFailedEvent,
+};
 use bevy_ecs::{
+    message::{MessageCursor, MessageReader, Messages},
     resource::Resource,
+    schedule::IntoScheduleConfigs,
     system::{Res, ResMut},
+    world::World,
+};
+use bevy_tasks::AsyncComputeTaskPool;
+use futures::{
+    future::{self, Either},
+    stream, FutureExt, Stream, StreamExt,
 };
-use futures::{FutureExt, Stream, StreamExt};
-use tokio::sync::watch;
+use tokio::sync::{oneshot, watch};
 use tokio_stream::wrappers::WatchStream;
 
-use crate::{TaskContext, WithWorld};
+use crate::{
+    message_stream::{MessageStream, MessageStreamStart},
+    TaskContext, WithWorld,
+};
 
 pub trait AsyncAssetTaskExt {
     fn get_load_state(
         &self,
         id: impl Into<UntypedAssetId> + Send + 'static,
     ) -> impl Stream<Item = RecursiveDependencyLoadState>;
+
+    /// Stream the [`LoadState`] of the asset `id`, which only covers the asset itself and not its
+    /// dependencies. Yields the current state first, then every change.
+    fn load_state_stream(
+        &self,
+        id: impl Into<UntypedAssetId> + Send + 'static,
+    ) -> impl Stream<Item = LoadState>;
+
+    /// Stream the [`DependencyLoadState`] of the asset `id`, which covers its direct dependencies
+    /// but not theirs. Yields the current state first, then every change. Changes to this state
+    /// aren't announced by any asset event, so it's checked every frame while subscribed.
+    fn dependency_load_state_stream(
+        &self,
+        id: impl Into<UntypedAssetId> + Send + 'static,
+    ) -> impl Stream<Item = DependencyLoadState>;
+
+    /// Stream the [`AssetEvent`]s about the asset `id` written after the stream subscribes:
+    /// [`Modified`], [`Removed`], [`Unused`] and [`LoadedWithDependencies`]. Useful for reacting
+    /// to hot reloads.
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_mod_async::prelude::*;
+    /// # use futures::StreamExt;
+    /// #[derive(Asset, TypePath)]
+    /// struct Level(u32);
+    ///
+    /// # App::new()
+    /// #     .add_plugins((MinimalPlugins, AssetPlugin::default(), AsyncTasksPlugin))
+    /// #     .init_asset::<Level>()
+    /// #     .add_systems(Startup, |world: &mut World| {
+    /// world.spawn_task(|cx| async move {
+    ///     let level = cx
+    ///         .with_world(|world| world.resource_mut::<Assets<Level>>().add(Level(1)))
+    ///         .await;
+    ///     let mut events = cx.asset_events(&level);
+    ///     cx.with_asset_mut(&level, |level| level.0 = 2).await.unwrap();
+    ///     assert!(events.next().await.unwrap().is_modified(&level));
+    /// #     cx.write_message(AppExit::Success).await;
+    /// });
+    /// #     })
+    /// #     .run();
+    /// ```
+    ///
+    /// [`Modified`]: AssetEvent::Modified
+    /// [`Removed`]: AssetEvent::Removed
+    /// [`Unused`]: AssetEvent::Unused
+    /// [`LoadedWithDependencies`]: AssetEvent::LoadedWithDependencies
+    fn asset_events<A: Asset>(
+        &self,
+        id: impl Into<AssetId<A>>,
+    ) -> impl Stream<Item = AssetEvent<A>> + Unpin;
+
+    /// Load every asset in `paths`. The returned [`LoadBatch`] resolves once all of them have
+    /// loaded along with their dependencies, or as soon as one fails.
+    fn load_assets<A: Asset, P: Into<AssetPath<'static>>>(
+        &self,
+        paths: impl IntoIterator<Item = P>,
+    ) -> LoadBatch<A>;
 }
 
 impl AsyncAssetTaskExt for TaskContext {
@@ -29,6 +107,225 @@ impl AsyncAssetTaskExt for TaskContext {
     ) -> impl Stream<Item = RecursiveDependencyLoadState> {
         LoadStateStream::new(self.clone(), id.into())
     }
+
+    fn load_state_stream(
+        &self,
+        id: impl Into<UntypedAssetId> + Send + 'static,
+    ) -> impl Stream<Item = LoadState> {
+        let states = AssetLoadStatesStream::new(self.clone(), id.into(), false);
+        changes(states, |states| states.load.clone())
+    }
+
+    fn dependency_load_state_stream(
+        &self,
+        id: impl Into<UntypedAssetId> + Send + 'static,
+    ) -> impl Stream<Item = DependencyLoadState> {
+        let states = AssetLoadStatesStream::new(self.clone(), id.into(), true);
+        changes(states, |states| states.dependencies.clone())
+    }
+
+    fn asset_events<A: Asset>(
+        &self,
+        id: impl Into<AssetId<A>>,
+    ) -> impl Stream<Item = AssetEvent<A>> + Unpin {
+        let id = id.into();
+        MessageStream::new_filter_map(self.clone(), MessageStreamStart::Now, move |event| {
+            (!matches!(event, AssetEvent::Added { .. }) && event_id(event) == id).then_some(*event)
+        })
+    }
+
+    fn load_assets<A: Asset, P: Into<AssetPath<'static>>>(
+        &self,
+        paths: impl IntoIterator<Item = P>,
+    ) -> LoadBatch<A> {
+        LoadBatch::new(self.clone(), paths.into_iter().map(Into::into).collect())
+    }
+}
+
+fn event_id<A: Asset>(event: &AssetEvent<A>) -> AssetId<A> {
+    match *event {
+        AssetEvent::Added { id }
+        | AssetEvent::Modified { id }
+        | AssetEvent::Removed { id }
+        | AssetEvent::Unused { id }
+        | AssetEvent::LoadedWithDependencies { id } => id,
+    }
+}
+
+/// Why an asset requested from a task didn't finish loading.
+///
+/// ```
+/// # use bevy::{asset::AssetLoadError, prelude::*};
+/// # use bevy_mod_async::{async_asset::AsyncAssetError, prelude::*};
+/// # #[derive(Asset, TypePath)]
+/// # struct Text(String);
+/// # async fn load(cx: TaskContext) {
+/// match cx.load_asset::<Text>("missing.txt").await {
+///     Err(AsyncAssetError::Load(error)) => {
+///         assert!(matches!(*error, AssetLoadError::AssetReaderError(_)));
+///     }
+///     _ => panic!("expected the asset itself to fail"),
+/// }
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub enum AsyncAssetError {
+    /// The asset isn't loaded or loading, for example because it was removed from its [`Assets`]
+    /// collection.
+    ///
+    /// [`Assets`]: bevy_asset::Assets
+    Unloaded,
+    /// The asset itself failed to load.
+    Load(Arc<AssetLoadError>),
+    /// The asset loaded, but something in its dependency tree failed. `dependency` is the path
+    /// of the asset that failed, if the error names one.
+    DependencyFailed {
+        dependency: Option<AssetPath<'static>>,
+        error: Arc<AssetLoadError>,
+    },
+    /// The task stopped receiving load state updates, which happens if [`AssetSubscriptions`] is
+    /// removed from the world.
+    SubscriptionClosed,
+}
+
+impl AsyncAssetError {
+    /// Classify a [`RecursiveDependencyLoadState::Failed`] error for the asset `id`. The failed
+    /// dependency is looked up in the recent [`UntypedAssetLoadFailedEvent`]s, falling back to
+    /// the path named in the error.
+    pub(crate) fn from_failure(
+        world: &World,
+        id: UntypedAssetId,
+        error: Arc<AssetLoadError>,
+    ) -> Self {
+        if let LoadState::Failed(_) = world.resource::<AssetServer>().load_state(id) {
+            return Self::Load(error);
+        }
+        let dependency = world
+            .get_resource::<Messages<UntypedAssetLoadFailedEvent>>()
+            .and_then(|messages| {
+                MessageCursor::default()
+                    .read(messages)
+                    .filter(|failed| failed.id != id && failed.error.eq(&error))
+                    .last()
+                    .map(|failed| failed.path.clone())
+            })
+            .or_else(|| failed_path(&error));
+        Self::DependencyFailed { dependency, error }
+    }
+}
+
+impl From<AssetLoadError> for AsyncAssetError {
+    fn from(error: AssetLoadError) -> Self {
+        Self::Load(Arc::new(error))
+    }
+}
+
+impl fmt::Display for AsyncAssetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unloaded => write!(f, "asset is not loaded"),
+            Self::Load(error) => write!(f, "{error}"),
+            Self::DependencyFailed {
+                dependency: Some(path),
+                error,
+            } => write!(f, "dependency '{path}' failed to load: {error}"),
+            Self::DependencyFailed {
+                dependency: None,
+                error,
+            } => write!(f, "a dependency failed to load: {error}"),
+            Self::SubscriptionClosed => write!(f, "asset load state subscription closed"),
+        }
+    }
+}
+
+impl std::error::Error for AsyncAssetError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Load(error) | Self::DependencyFailed { error, .. } => Some(error.as_ref()),
+            Self::Unloaded | Self::SubscriptionClosed => None,
+        }
+    }
+}
+
+/// Why [`CommonUsesTaskExt::save_asset`] failed.
+///
+/// [`CommonUsesTaskExt::save_asset`]: crate::common_uses::CommonUsesTaskExt::save_asset
+#[derive(Debug)]
+pub enum AssetSaveError {
+    /// The asset isn't in its [`Assets`] collection.
+    ///
+    /// [`Assets`]: bevy_asset::Assets
+    Unloaded,
+    /// The path names an asset source that isn't registered.
+    MissingSource(MissingAssetSourceError),
+    /// The asset source can't be written to.
+    MissingWriter(MissingAssetWriterError),
+    /// Opening or flushing the writer failed.
+    Write(AssetWriterError),
+    /// The [`AssetSaver`](bevy_asset::saver::AssetSaver) failed.
+    Saver(Box<dyn std::error::Error + Send + Sync>),
+}
+
+impl From<MissingAssetSourceError> for AssetSaveError {
+    fn from(error: MissingAssetSourceError) -> Self {
+        Self::MissingSource(error)
+    }
+}
+
+impl From<MissingAssetWriterError> for AssetSaveError {
+    fn from(error: MissingAssetWriterError) -> Self {
+        Self::MissingWriter(error)
+    }
+}
+
+impl From<AssetWriterError> for AssetSaveError {
+    fn from(error: AssetWriterError) -> Self {
+        Self::Write(error)
+    }
+}
+
+impl fmt::Display for AssetSaveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unloaded => write!(f, "asset is not loaded"),
+            Self::MissingSource(error) => write!(f, "{error}"),
+            Self::MissingWriter(error) => write!(f, "{error}"),
+            Self::Write(error) => write!(f, "{error}"),
+            Self::Saver(error) => write!(f, "failed to save asset: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for AssetSaveError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Unloaded => None,
+            Self::MissingSource(error) => Some(error),
+            Self::MissingWriter(error) => Some(error),
+            Self::Write(error) => Some(error),
+            Self::Saver(error) => Some(error.as_ref()),
+        }
+    }
+}
+
+/// The path of the asset an [`AssetLoadError`] is about, for the variants that carry one.
+fn failed_path(error: &AssetLoadError) -> Option<AssetPath<'static>> {
+    match error {
+        AssetLoadError::RequestedHandleTypeMismatch { path, .. }
+        | AssetLoadError::DeserializeMeta { path, .. }
+        | AssetLoadError::CannotLoadProcessedAsset { path }
+        | AssetLoadError::CannotLoadIgnoredAsset { path }
+        | AssetLoadError::AssetLoaderPanic { path, .. }
+        | AssetLoadError::MissingLabel {
+            base_path: path, ..
+        } => Some(path.clone()),
+        AssetLoadError::AssetLoaderError(error) => Some(error.path().clone()),
+        AssetLoadError::MissingAssetLoader {
+            asset_path: Some(path),
+            ..
+        } => Some(AssetPath::parse(path).into_owned()),
+        _ => None,
+    }
 }
 
 /// Because we can't implement [PartialEq] on a foreign type, create our own trait that mirrors the interface
@@ -143,6 +440,24 @@ impl PartialEquality for AssetLoadError {
     }
 }
 
+impl PartialEquality for LoadState {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Failed(l0), Self::Failed(r0)) => l0.eq(r0),
+            _ => core::mem::discriminant(self) == core::mem::discriminant(other),
+        }
+    }
+}
+
+impl PartialEquality for DependencyLoadState {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Failed(l0), Self::Failed(r0)) => l0.eq(r0),
+            _ => core::mem::discriminant(self) == core::mem::discriminant(other),
+        }
+    }
+}
+
 impl PartialEquality for RecursiveDependencyLoadState {
     fn eq(&self, other: &Self) -> bool {
         match (self, other) {
@@ -152,86 +467,456 @@ impl PartialEquality for RecursiveDependencyLoadState {
     }
 }
 
-/// Notifies interested parties of changes to asset load states. Iterates asset handles
-/// registered in [`AssetSubscriptions`] and checks their load state, emitting an update to
-/// all subscribers if the state has changed.
+impl PartialEquality for AssetLoadStates {
+    fn eq(&self, other: &Self) -> bool {
+        self.load.eq(&other.load)
+            && self.dependencies.eq(&other.dependencies)
+            && self.recursive.eq(&other.recursive)
+    }
+}
+
+/// How much of an asset's dependency tree to wait for when loading it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LoadDepth {
+    /// Just the asset itself, as reported by its [`LoadState`].
+    Asset,
+    /// The asset and its direct dependencies, as reported by its [`DependencyLoadState`].
+    Dependencies,
+    /// The asset and its whole dependency tree, as reported by its
+    /// [`RecursiveDependencyLoadState`].
+    #[default]
+    Recursive,
+}
+
+/// The load states the [`AssetServer`] tracks for an asset, which is what
+/// [`AssetSubscriptions`] sends to subscribers.
+#[derive(Debug, Clone)]
+pub struct AssetLoadStates {
+    pub load: LoadState,
+    pub dependencies: DependencyLoadState,
+    pub recursive: RecursiveDependencyLoadState,
+}
+
+impl AssetLoadStates {
+    /// The current load states of `id`, which are all `NotLoaded` if the server doesn't know
+    /// about it.
+    pub fn of(assets: &AssetServer, id: impl Into<UntypedAssetId>) -> Self {
+        match assets.get_load_states(id) {
+            Some((load, dependencies, recursive)) => Self {
+                load,
+                dependencies,
+                recursive,
+            },
+            None => Self {
+                load: LoadState::NotLoaded,
+                dependencies: DependencyLoadState::NotLoaded,
+                recursive: RecursiveDependencyLoadState::NotLoaded,
+            },
+        }
+    }
+
+    /// The state at `depth`, in terms of [`RecursiveDependencyLoadState`] since all three
+    /// states have the same variants.
+    pub(crate) fn at_depth(&self, depth: LoadDepth) -> RecursiveDependencyLoadState {
+        match depth {
+            LoadDepth::Asset => match &self.load {
+                LoadState::NotLoaded => RecursiveDependencyLoadState::NotLoaded,
+                LoadState::Loading => RecursiveDependencyLoadState::Loading,
+                LoadState::Loaded => RecursiveDependencyLoadState::Loaded,
+                LoadState::Failed(error) => RecursiveDependencyLoadState::Failed(error.clone()),
+            },
+            LoadDepth::Dependencies => match &self.dependencies {
+                DependencyLoadState::NotLoaded => RecursiveDependencyLoadState::NotLoaded,
+                DependencyLoadState::Loading => RecursiveDependencyLoadState::Loading,
+                DependencyLoadState::Loaded => RecursiveDependencyLoadState::Loaded,
+                DependencyLoadState::Failed(error) => {
+                    RecursiveDependencyLoadState::Failed(error.clone())
+                }
+            },
+            LoadDepth::Recursive => self.recursive.clone(),
+        }
+    }
+}
+
+/// Tracks the asset events of type `A`, so load state subscriptions to `A`s are only checked when
+/// something happens to them rather than every frame. This is registered the first time a task
+/// waits on an `A` through a typed API such as [`CommonUsesTaskExt::load_asset`], unless [`Last`]
+/// is running at the time, in which case subscriptions to `A`s fall back to being checked every
+/// frame unless this plugin is added up front.
+///
+/// [`CommonUsesTaskExt::load_asset`]: crate::common_uses::CommonUsesTaskExt::load_asset
+pub fn asset_subscriptions_plugin<A: Asset>(app: &mut App) {
+    app.world_mut()
+        .get_resource_or_init::<AssetSubscriptions>()
+        .tracked_types
+        .insert(TypeId::of::<A>());
+    app.add_systems(Last, track_asset_events::<A>.before(notify_asset_events));
+}
+
+fn track_asset_type<A: Asset>(world: &mut World) {
+    let mut subscriptions = world.resource_mut::<AssetSubscriptions>();
+    if !subscriptions.tracked_types.insert(TypeId::of::<A>()) {
+        return;
+    }
+    let registered = world.try_schedule_scope(Last, |_, schedule| {
+        schedule.add_systems(track_asset_events::<A>.before(notify_asset_events));
+    });
+    if registered.is_err() {
+        // Keep checking these subscriptions every frame instead
+        world
+            .resource_mut::<AssetSubscriptions>()
+            .tracked_types
+            .remove(&TypeId::of::<A>());
+    }
+}
+
+/// Marks subscribed `A`s as changed when an [`AssetEvent`] is written for them, so
+/// [`notify_asset_events`] checks their load state. Runs in [`Last`], since asset events are
+/// written in `PostUpdate`.
+pub fn track_asset_events<A: Asset>(
+    mut events: MessageReader<AssetEvent<A>>,
+    mut subscriptions: ResMut<AssetSubscriptions>,
+) {
+    for event in events.read() {
+        let id = event_id(event).untyped();
+        if subscriptions.handles.contains_key(&id) {
+            subscriptions.changed.insert(id);
+        }
+    }
+}
+
+/// Notifies interested parties of changes to asset load states. Checks the load state of the
+/// assets in [`AssetSubscriptions`] that [`track_asset_events`] has seen events for, or every
+/// subscribed asset if something failed to load since a failure propagates to everything that
+/// depends on it. Assets whose type isn't tracked, or whose [`DependencyLoadState`] is being
+/// watched, are checked every frame. Emits an update to all subscribers if the state has changed.
+/// Runs in [`Last`] after every [`track_asset_events`], so loads finish the frame their events
+/// are written.
 pub fn notify_asset_events(
     mut subscriptions: ResMut<AssetSubscriptions>,
+    mut failures: MessageReader<UntypedAssetLoadFailedEvent>,
     assets: Res<AssetServer>,
 ) {
-    let mut to_unsubscribe = Vec::new();
-    for (id, tx) in &subscriptions.handles {
-        let current = assets.recursive_dependency_load_state(*id);
-        if !current.eq(&tx.borrow()) && tx.send(current).is_err() {
-            // Channel is closed, unsubscribe this asset
-            to_unsubscribe.push(*id);
+    let any_failed = failures.read().count() > 0;
+    let subscriptions = &mut *subscriptions;
+    let changed = std::mem::take(&mut subscriptions.changed);
+    let tracked_types = &subscriptions.tracked_types;
+    let polled = &mut subscriptions.polled;
+    subscriptions.handles.retain(|id, tx| {
+        if tx.is_closed() {
+            polled.remove(id);
+            return false;
         }
-    }
-    for id in to_unsubscribe {
-        subscriptions.handles.remove(&id);
-    }
+        if any_failed
+            || changed.contains(id)
+            || polled.contains(id)
+            || !tracked_types.contains(&id.type_id())
+        {
+            let current = AssetLoadStates::of(&assets, *id);
+            tx.send_if_modified(|state| {
+                let modified = !state.eq(&current);
+                *state = current;
+                modified
+            });
+        }
+        true
+    });
 }
 
 /// Manages interest in assets. Maintains a [`tokio::sync::watch::Sender`] for each asset
-/// handle a client has expressed interest in. [`AssetSubscriptions::subscribe_to`] is used
-/// to express interest in the load state for a given asset.
+/// handle a client has expressed interest in, shared by every subscriber to that asset.
+/// [`AssetSubscriptions::subscribe_to`] is used to express interest in the load state for a
+/// given asset.
 #[derive(Default, Resource)]
 pub struct AssetSubscriptions {
-    handles: HashMap<UntypedAssetId, watch::Sender<RecursiveDependencyLoadState>>,
+    handles: HashMap<UntypedAssetId, watch::Sender<AssetLoadStates>>,
+    changed: HashSet<UntypedAssetId>,
+    polled: HashSet<UntypedAssetId>,
+    tracked_types: HashSet<TypeId>,
 }
 
 impl AssetSubscriptions {
     /// Subscribe to all asset load events for an asset. The resulting channel will
     /// immediately yield the current load state for the given asset, and subsequent changes
-    /// to the load state will generate additional change events.
+    /// to the load state will generate additional change events. Subscribing to an asset that
+    /// already has subscribers joins their channel, updating it to `init` if it's out of date.
+    ///
+    /// ```
+    /// # use bevy::{asset::{LoadedFolder, UntypedAssetId}, prelude::*};
+    /// # use bevy_mod_async::async_asset::{AssetLoadStates, AssetSubscriptions};
+    /// # let mut app = App::new();
+    /// # app.add_plugins((MinimalPlugins, AssetPlugin::default()));
+    /// let assets = app.world().resource::<AssetServer>();
+    /// let mut subscriptions = AssetSubscriptions::default();
+    /// let folder = assets.load_folder("text");
+    /// let id = UntypedAssetId::from(&folder);
+    /// let first = subscriptions.subscribe_to(id, AssetLoadStates::of(assets, id));
+    /// let unloaded = UntypedAssetId::from(AssetId::<LoadedFolder>::default());
+    /// let second = subscriptions.subscribe_to(id, AssetLoadStates::of(assets, unloaded));
+    /// assert!(first.has_changed().unwrap());
+    /// assert!(first.same_channel(&second));
+    /// ```
     pub fn subscribe_to(
         &mut self,
         id: UntypedAssetId,
-        init: RecursiveDependencyLoadState,
-    ) -> watch::Receiver<RecursiveDependencyLoadState> {
-        let (tx, rx) = watch::channel(init);
-        self.handles.insert(id, tx);
-        rx
+        init: AssetLoadStates,
+    ) -> watch::Receiver<AssetLoadStates> {
+        match self.handles.entry(id) {
+            Entry::Occupied(entry) => {
+                let tx = entry.get();
+                tx.send_if_modified(|state| {
+                    let modified = !state.eq(&init);
+                    *state = init;
+                    modified
+                });
+                tx.subscribe()
+            }
+            Entry::Vacant(entry) => {
+                let (tx, rx) = watch::channel(init);
+                entry.insert(tx);
+                rx
+            }
+        }
     }
 }
 
-enum LoadStateStreamState {
-    AwaitingWorld(WithWorld<watch::Receiver<RecursiveDependencyLoadState>>),
-    HasStream(WatchStream<RecursiveDependencyLoadState>),
+enum AssetLoadStatesStreamState {
+    AwaitingWorld(WithWorld<watch::Receiver<AssetLoadStates>>),
+    HasStream(WatchStream<AssetLoadStates>),
 }
 
-pub struct LoadStateStream {
-    state: LoadStateStreamState,
+/// Streams every change to the [`AssetLoadStates`] of an asset.
+pub(crate) struct AssetLoadStatesStream {
+    state: AssetLoadStatesStreamState,
 }
 
-impl LoadStateStream {
-    pub fn new(cx: TaskContext, id: UntypedAssetId) -> Self {
+impl AssetLoadStatesStream {
+    /// Subscribe to the asset `id`. If `poll` is set the asset is checked every frame, which is
+    /// needed to notice changes to its [`DependencyLoadState`].
+    pub(crate) fn new(cx: TaskContext, id: UntypedAssetId, poll: bool) -> Self {
+        let fut = cx.with_world(move |world| subscribe_to_load_states(world, id, poll));
+        Self {
+            state: AssetLoadStatesStreamState::AwaitingWorld(fut),
+        }
+    }
+
+    /// Like [`AssetLoadStatesStream::new`], but knowing the asset type lets the subscription be
+    /// driven by [`AssetEvent<A>`]s. See [`asset_subscriptions_plugin`].
+    pub(crate) fn for_asset<A: Asset>(cx: TaskContext, id: AssetId<A>, poll: bool) -> Self {
         let fut = cx.with_world(move |world| {
-            let assets = world.resource::<AssetServer>();
-            let init = assets.recursive_dependency_load_state(id);
-            world
-                .resource_mut::<AssetSubscriptions>()
-                .subscribe_to(id, init)
+            track_asset_type::<A>(world);
+            subscribe_to_load_states(world, id.untyped(), poll)
         });
         Self {
-            state: LoadStateStreamState::AwaitingWorld(fut),
+            state: AssetLoadStatesStreamState::AwaitingWorld(fut),
         }
     }
 }
 
-impl Stream for LoadStateStream {
-    type Item = RecursiveDependencyLoadState;
+fn subscribe_to_load_states(
+    world: &mut World,
+    id: UntypedAssetId,
+    poll: bool,
+) -> watch::Receiver<AssetLoadStates> {
+    let init = AssetLoadStates::of(world.resource::<AssetServer>(), id);
+    let mut subscriptions = world.resource_mut::<AssetSubscriptions>();
+    if poll {
+        subscriptions.polled.insert(id);
+    }
+    subscriptions.subscribe_to(id, init)
+}
+
+impl Stream for AssetLoadStatesStream {
+    type Item = AssetLoadStates;
 
     fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
         match &mut self.state {
-            LoadStateStreamState::AwaitingWorld(fut) => match fut.poll_unpin(cx) {
+            AssetLoadStatesStreamState::AwaitingWorld(fut) => match fut.poll_unpin(cx) {
                 Poll::Ready(rx) => {
-                    self.state = LoadStateStreamState::HasStream(WatchStream::new(rx));
+                    self.state = AssetLoadStatesStreamState::HasStream(WatchStream::new(rx));
                     self.poll_next(cx)
                 }
                 Poll::Pending => Poll::Pending,
             },
-            LoadStateStreamState::HasStream(rx) => rx.poll_next_unpin(cx),
+            AssetLoadStatesStreamState::HasStream(rx) => rx.poll_next_unpin(cx),
+        }
+    }
+}
+
+/// Map each [`AssetLoadStates`] to one of its states, skipping updates that leave it unchanged.
+fn changes<S: Clone + PartialEquality>(
+    states: AssetLoadStatesStream,
+    get: fn(&AssetLoadStates) -> S,
+) -> impl Stream<Item = S> + Unpin {
+    let mut last = None::<S>;
+    states.filter_map(move |states| {
+        let state = get(&states);
+        let changed = last.as_ref().is_none_or(|last| !last.eq(&state));
+        if changed {
+            last = Some(state.clone());
+        }
+        future::ready(changed.then_some(state))
+    })
+}
+
+/// Streams the [`RecursiveDependencyLoadState`] of an asset, starting with its current state.
+pub struct LoadStateStream {
+    inner: Pin<Box<dyn Stream<Item = RecursiveDependencyLoadState> + Send>>,
+}
+
+impl LoadStateStream {
+    pub fn new(cx: TaskContext, id: UntypedAssetId) -> Self {
+        Self::from_states(AssetLoadStatesStream::new(cx, id, false))
+    }
+
+    /// Like [`LoadStateStream::new`], but knowing the asset type lets the subscription be
+    /// driven by [`AssetEvent<A>`]s. See [`asset_subscriptions_plugin`].
+    pub fn for_asset<A: Asset>(cx: TaskContext, id: AssetId<A>) -> Self {
+        Self::from_states(AssetLoadStatesStream::for_asset(cx, id, false))
+    }
+
+    fn from_states(states: AssetLoadStatesStream) -> Self {
+        Self {
+            inner: Box::pin(changes(states, |states| states.recursive.clone())),
+        }
+    }
+}
+
+impl Stream for LoadStateStream {
+    type Item = RecursiveDependencyLoadState;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.inner.poll_next_unpin(cx)
+    }
+}
+
+/// How far a [`LoadBatch`] has got. Assets count as loaded once their dependencies have loaded
+/// too.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LoadProgress {
+    pub loaded: usize,
+    pub failed: usize,
+    pub total: usize,
+}
+
+impl LoadProgress {
+    /// The fraction of assets that have loaded, from `0.0` to `1.0`. An empty batch counts as
+    /// fully loaded.
+    pub fn fraction(&self) -> f32 {
+        if self.total == 0 {
+            1.0
+        } else {
+            self.loaded as f32 / self.total as f32
+        }
+    }
+}
+
+/// A group of assets being loaded together, created with [`AsyncAssetTaskExt::load_assets`].
+/// Await it to get the handles, in the same order as the paths. Loading is driven by a
+/// background task, so [`LoadBatch::progress`] keeps updating while the batch itself isn't
+/// being polled. Dropping the batch stops that task.
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_mod_async::prelude::*;
+/// # use futures::StreamExt;
+/// # #[derive(Asset, TypePath)]
+/// # struct Text(String);
+/// # async fn load(cx: TaskContext) {
+/// let batch = cx.load_assets::<Text, _>(["text/hello.txt", "text/world.txt"]);
+/// let mut progress = batch.progress();
+/// while let Some(update) = progress.next().await {
+///     // Drive a loading bar with `update.fraction()`
+/// }
+/// let [hello, world] = batch.await.unwrap().try_into().unwrap();
+/// # }
+/// ```
+pub struct LoadBatch<A: Asset> {
+    result: oneshot::Receiver<Result<Vec<Handle<A>>, AsyncAssetError>>,
+    progress: watch::Receiver<LoadProgress>,
+}
+
+impl<A: Asset> LoadBatch<A> {
+    pub fn new(cx: TaskContext, paths: Vec<AssetPath<'static>>) -> Self {
+        let (progress_tx, progress) = watch::channel(LoadProgress {
+            total: paths.len(),
+            ..Default::default()
+        });
+        let (mut result_tx, result) = oneshot::channel();
+        AsyncComputeTaskPool::get()
+            .spawn(async move {
+                let loaded = {
+                    let load = pin!(load_batch(cx, paths, progress_tx));
+                    let cancelled = pin!(result_tx.closed());
+                    match future::select(load, cancelled).await {
+                        Either::Left((loaded, _)) => loaded,
+                        Either::Right(_) => return,
+                    }
+                };
+                result_tx.send(loaded).ok();
+            })
+            .detach();
+        Self { result, progress }
+    }
+
+    /// Stream the progress of this batch, starting with its current progress. Ends once the
+    /// batch has finished, successfully or not.
+    pub fn progress(&self) -> impl Stream<Item = LoadProgress> + Unpin {
+        WatchStream::new(self.progress.clone())
+    }
+}
+
+impl<A: Asset> Future for LoadBatch<A> {
+    type Output = Result<Vec<Handle<A>>, AsyncAssetError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.result
+            .poll_unpin(cx)
+            .map(|result| result.unwrap_or(Err(AsyncAssetError::SubscriptionClosed)))
+    }
+}
+
+async fn load_batch<A: Asset>(
+    cx: TaskContext,
+    paths: Vec<AssetPath<'static>>,
+    progress: watch::Sender<LoadProgress>,
+) -> Result<Vec<Handle<A>>, AsyncAssetError> {
+    let handles = cx
+        .with_world(|world| {
+            let assets = world.resource::<AssetServer>();
+            paths
+                .into_iter()
+                .map(|path| assets.load(path))
+                .collect::<Vec<_>>()
+        })
+        .await;
+    let mut states = stream::select_all(handles.iter().enumerate().map(|(i, handle)| {
+        LoadStateStream::for_asset(cx.clone(), handle.id()).map(move |state| (i, state))
+    }));
+    let mut loaded = vec![false; handles.len()];
+    let mut current = *progress.borrow();
+    while current.loaded < current.total {
+        let Some((i, state)) = states.next().await else {
+            return Err(AsyncAssetError::SubscriptionClosed);
+        };
+        match state {
+            RecursiveDependencyLoadState::NotLoaded => return Err(AsyncAssetError::Unloaded),
+            RecursiveDependencyLoadState::Loading => continue,
+            RecursiveDependencyLoadState::Loaded if !loaded[i] => {
+                loaded[i] = true;
+                current.loaded += 1;
+            }
+            RecursiveDependencyLoadState::Loaded => continue,
+            RecursiveDependencyLoadState::Failed(error) => {
+                current.failed += 1;
+                progress.send_replace(current);
+                let id = handles[i].id().untyped();
+                return Err(cx
+                    .with_world(move |world| AsyncAssetError::from_failure(world, id, error))
+                    .await);
+            }
         }
+        progress.send_replace(current);
     }
+    Ok(handles)
 }
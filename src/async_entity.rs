@@ -1,7 +1,8 @@
-use crate::{TaskContext, WithWorld};
+use crate::{event_stream::EventStream, TaskContext, WithWorld};
 use bevy_ecs::{
     bundle::{Bundle, BundleFromComponents},
     entity::Entity,
+    event::EntityEvent,
     world::{error::EntityMutableFetchError, EntityWorldMut},
 };
 
@@ -55,6 +56,50 @@ impl AsyncEntity {
         self.task_context
             .with_world(move |world| world.entity_mut(e).take::<T>())
     }
+
+    /// Stream every `E` triggered on this entity. This spawns an [`Observer`] watching the entity,
+    /// which is despawned when the stream is dropped. The stream ends when the entity is
+    /// despawned, including when it's already gone by the time the observer would be spawned.
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_mod_async::prelude::*;
+    /// # use futures::StreamExt;
+    /// #[derive(EntityEvent, Clone)]
+    /// struct Damaged {
+    ///     entity: Entity,
+    ///     amount: u32,
+    /// }
+    ///
+    /// # App::new()
+    /// #     .add_plugins((MinimalPlugins, AssetPlugin::default(), AsyncTasksPlugin))
+    /// #     .add_systems(Startup, |world: &mut World| {
+    /// world.spawn_task(|cx| async move {
+    ///     let player = cx.spawn(()).await;
+    ///     let other = cx.spawn(()).await;
+    ///     let mut damage = cx.entity(player).observe::<Damaged>();
+    ///     cx.with_world(move |world| {
+    ///         world.trigger(Damaged { entity: other, amount: 1 });
+    ///         world.trigger(Damaged { entity: player, amount: 2 });
+    ///     })
+    ///     .await;
+    ///     assert_eq!(damage.next().await.unwrap().amount, 2);
+    ///     cx.despawn(player).await;
+    ///     assert!(damage.next().await.is_none());
+    ///
+    ///     let ghost = cx.spawn(()).await;
+    ///     cx.despawn(ghost).detach();
+    ///     assert!(cx.entity(ghost).observe::<Damaged>().next().await.is_none());
+    /// #     cx.write_message(AppExit::Success).await;
+    /// });
+    /// #     })
+    /// #     .run();
+    /// ```
+    ///
+    /// [`Observer`]: bevy_ecs::observer::Observer
+    pub fn observe<E: EntityEvent + Clone>(&self) -> EventStream<E> {
+        EventStream::new(self.task_context.clone(), Some(self.entity))
+    }
 }
 
 pub trait AsyncEntityTaskExt {
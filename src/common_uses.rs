@@ -4,23 +4,267 @@ use crate::{TaskContext, WithWorld};
 
 #[cfg(feature = "asset")]
 use {
-    crate::async_asset::AsyncAssetTaskExt,
+    crate::async_asset::{AssetLoadStatesStream, AssetSaveError, AsyncAssetError, LoadDepth},
     bevy_asset::{
-        Asset, AssetLoadError, AssetPath, AssetServer, Handle, RecursiveDependencyLoadState,
+        io::AssetWriterError,
+        meta::Settings,
+        saver::{AssetSaver, SavedAsset},
+        Asset, AssetId, AssetLoader, AssetPath, AssetServer, Assets, ErasedLoadedAsset, Handle,
+        LoadedAsset, LoadedFolder, LoadedUntypedAsset, RecursiveDependencyLoadState, UntypedHandle,
     },
-    futures::StreamExt,
+    bevy_tasks::IoTaskPool,
+    futures::{AsyncWriteExt, StreamExt},
     std::future::Future,
 };
 
+#[cfg(feature = "asset_processor")]
+use bevy_asset::processor::AssetProcessor;
+
 pub trait CommonUsesTaskExt {
     fn spawn(&self, bundle: impl Bundle) -> WithWorld<Entity>;
     fn despawn(&self, e: Entity) -> WithWorld<bool>;
 
+    /// Load the asset at `path` and wait for it to finish loading along with its dependencies.
+    /// Any number of tasks can load the same asset at once.
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_mod_async::prelude::*;
+    /// # #[derive(Asset, TypePath)]
+    /// # struct Text(String);
+    /// # async fn load(cx: TaskContext) {
+    /// let (first, second) = futures::join!(
+    ///     cx.load_asset::<Text>("text/hello.txt"),
+    ///     cx.load_asset::<Text>("text/hello.txt"),
+    /// );
+    /// // Both tasks get the same handle
+    /// assert_eq!(first.unwrap(), second.unwrap());
+    /// # }
+    /// ```
     #[cfg(feature = "asset")]
     fn load_asset<'a, A: Asset>(
         &self,
         path: impl Into<AssetPath<'a>> + Send + 'static,
-    ) -> impl Future<Output = Result<Handle<A>, AssetLoadError>> + Send;
+    ) -> impl Future<Output = Result<Handle<A>, AsyncAssetError>> + Send;
+
+    /// Like [`CommonUsesTaskExt::load_asset`], but only waits for as much of the asset's
+    /// dependency tree as `depth` asks for.
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_mod_async::{async_asset::{AsyncAssetError, LoadDepth}, prelude::*};
+    /// # #[derive(Asset, TypePath)]
+    /// # struct Text(String);
+    /// // Loading a `Playlist` also loads every track listed in it
+    /// #[derive(Asset, TypePath)]
+    /// struct Playlist {
+    ///     #[dependency]
+    ///     tracks: Vec<Handle<Text>>,
+    /// }
+    ///
+    /// # async fn load(cx: TaskContext) {
+    /// let path = "playlists/broken.playlist";
+    /// // The playlist itself can load even if one of its tracks is missing
+    /// let playlist = cx.load_asset_with_depth::<Playlist>(path, LoadDepth::Asset).await;
+    /// assert!(playlist.is_ok());
+    /// let playlist = cx.load_asset::<Playlist>(path).await;
+    /// assert!(matches!(playlist, Err(AsyncAssetError::DependencyFailed { .. })));
+    /// # }
+    /// ```
+    #[cfg(feature = "asset")]
+    fn load_asset_with_depth<'a, A: Asset>(
+        &self,
+        path: impl Into<AssetPath<'a>> + Send + 'static,
+        depth: LoadDepth,
+    ) -> impl Future<Output = Result<Handle<A>, AsyncAssetError>> + Send;
+
+    /// Like [`CommonUsesTaskExt::load_asset`], but lets `settings` override the loader settings
+    /// for this load, for example to pick an image's sampler.
+    #[cfg(feature = "asset")]
+    fn load_asset_with_settings<'a, A: Asset, S: Settings>(
+        &self,
+        path: impl Into<AssetPath<'a>> + Send + 'static,
+        settings: impl Fn(&mut S) + Send + Sync + 'static,
+    ) -> impl Future<Output = Result<Handle<A>, AsyncAssetError>> + Send;
+
+    /// Like [`CommonUsesTaskExt::load_asset`], but holds on to `guard` until the asset has
+    /// loaded or failed to load. See [`AssetServer::load_acquire`].
+    #[cfg(feature = "asset")]
+    fn load_asset_acquire<'a, A: Asset, G: Send + Sync + 'static>(
+        &self,
+        path: impl Into<AssetPath<'a>> + Send + 'static,
+        guard: G,
+    ) -> impl Future<Output = Result<Handle<A>, AsyncAssetError>> + Send;
+
+    /// Load the asset at `path` without knowing its type, which is picked by the loader for its
+    /// extension.
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_mod_async::prelude::*;
+    /// # #[derive(Asset, TypePath)]
+    /// # struct Text(String);
+    /// # async fn load(cx: TaskContext) {
+    /// let handle = cx.load_untyped("text/hello.txt").await.unwrap();
+    /// assert!(handle.try_typed::<Text>().is_ok());
+    /// # }
+    /// ```
+    #[cfg(feature = "asset")]
+    fn load_untyped<'a>(
+        &self,
+        path: impl Into<AssetPath<'a>> + Send + 'static,
+    ) -> impl Future<Output = Result<UntypedHandle, AsyncAssetError>> + Send;
+
+    /// Load every asset in the folder at `path`, waiting for them to load along with their
+    /// dependencies like [`CommonUsesTaskExt::load_asset`] does.
+    #[cfg(feature = "asset")]
+    fn load_folder<'a>(
+        &self,
+        path: impl Into<AssetPath<'a>> + Send + 'static,
+    ) -> impl Future<Output = Result<Vec<UntypedHandle>, AsyncAssetError>> + Send;
+
+    /// Like [`CommonUsesTaskExt::load_folder`], but only returns the handles to assets of type
+    /// `A`.
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_mod_async::prelude::*;
+    /// # #[derive(Asset, TypePath)]
+    /// # struct Text(String);
+    /// # async fn load(cx: TaskContext) {
+    /// let texts = cx.load_folder_of::<Text>("text").await.unwrap();
+    /// # }
+    /// ```
+    #[cfg(feature = "asset")]
+    fn load_folder_of<'a, A: Asset>(
+        &self,
+        path: impl Into<AssetPath<'a>> + Send + 'static,
+    ) -> impl Future<Output = Result<Vec<Handle<A>>, AsyncAssetError>> + Send;
+
+    /// Load an asset with [`CommonUsesTaskExt::load_asset`] and return a clone of it. The handle
+    /// is dropped afterwards, so the asset may be unloaded unless something else holds on to it.
+    #[cfg(feature = "asset")]
+    fn load_asset_value<'a, A: Asset + Clone>(
+        &self,
+        path: impl Into<AssetPath<'a>> + Send + 'static,
+    ) -> impl Future<Output = Result<A, AsyncAssetError>> + Send;
+
+    /// Run `f` against the asset `id` in [`Assets<A>`]. Fails with [`AsyncAssetError::Unloaded`]
+    /// if the asset isn't present.
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_mod_async::{async_asset::AsyncAssetError, prelude::*};
+    /// #[derive(Asset, TypePath)]
+    /// struct Score(u32);
+    ///
+    /// # App::new()
+    /// #     .add_plugins((MinimalPlugins, AssetPlugin::default(), AsyncTasksPlugin))
+    /// #     .init_asset::<Score>()
+    /// #     .add_systems(Startup, |world: &mut World| {
+    /// world.spawn_task(|cx| async move {
+    ///     let score = cx
+    ///         .with_world(|world| world.resource_mut::<Assets<Score>>().add(Score(1)))
+    ///         .await;
+    ///     cx.with_asset_mut(&score, |score| score.0 += 1).await.unwrap();
+    ///     assert_eq!(cx.with_asset(&score, |score| score.0).await.unwrap(), 2);
+    ///
+    ///     let id = score.id();
+    ///     cx.with_world(move |world| world.resource_mut::<Assets<Score>>().remove(id))
+    ///         .await;
+    ///     let missing = cx.with_asset(&score, |score| score.0).await;
+    ///     assert!(matches!(missing, Err(AsyncAssetError::Unloaded)));
+    /// #     cx.write_message(AppExit::Success).await;
+    /// });
+    /// #     })
+    /// #     .run();
+    /// ```
+    #[cfg(feature = "asset")]
+    fn with_asset<A: Asset, R: Send + 'static>(
+        &self,
+        id: impl Into<AssetId<A>>,
+        f: impl FnOnce(&A) -> R + Send + 'static,
+    ) -> WithWorld<Result<R, AsyncAssetError>>;
+
+    /// Like [`CommonUsesTaskExt::with_asset`], but gives `f` mutable access. The asset is marked
+    /// as modified even if `f` doesn't change it.
+    #[cfg(feature = "asset")]
+    fn with_asset_mut<A: Asset, R: Send + 'static>(
+        &self,
+        id: impl Into<AssetId<A>>,
+        f: impl FnOnce(&mut A) -> R + Send + 'static,
+    ) -> WithWorld<Result<R, AsyncAssetError>>;
+
+    /// Save the asset `id` to `path` with `saver`, writing through the [`AssetSource`] the path
+    /// names. The saver runs on the [`IoTaskPool`]. Resolves to the settings the saver picked for
+    /// its [`AssetSaver::OutputLoader`], which can be written to a `.meta` file if the saved
+    /// asset should be loaded with them.
+    ///
+    /// ```
+    /// # use bevy::{
+    /// #     asset::{io::{AsyncWriteExt, Writer}, saver::{AssetSaver, SavedAsset}},
+    /// #     prelude::*,
+    /// # };
+    /// # use bevy_mod_async::prelude::*;
+    /// # #[derive(Asset, TypePath, Clone)]
+    /// # struct Text(String);
+    /// struct TextSaver;
+    ///
+    /// impl AssetSaver for TextSaver {
+    ///     type Asset = Text;
+    ///     type Settings = ();
+    ///     // Saved text isn't loaded back with any particular settings
+    ///     type OutputLoader = ();
+    ///     type Error = std::io::Error;
+    ///
+    ///     async fn save(
+    ///         &self,
+    ///         writer: &mut Writer,
+    ///         text: SavedAsset<'_, Text>,
+    ///         _: &(),
+    ///     ) -> Result<(), Self::Error> {
+    ///         writer.write_all(text.0.as_bytes()).await
+    ///     }
+    /// }
+    ///
+    /// # async fn save(cx: TaskContext, text: Handle<Text>) {
+    /// cx.save_asset(&text, "saves://generated.txt", TextSaver)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    ///
+    /// [`AssetSource`]: bevy_asset::io::AssetSource
+    /// [`IoTaskPool`]: bevy_tasks::IoTaskPool
+    #[cfg(feature = "asset")]
+    fn save_asset<'a, S: AssetSaver>(
+        &self,
+        id: impl Into<AssetId<S::Asset>>,
+        path: impl Into<AssetPath<'a>>,
+        saver: S,
+    ) -> impl Future<Output = Result<<S::OutputLoader as AssetLoader>::Settings, AssetSaveError>> + Send
+    where
+        S::Asset: Clone;
+
+    /// Like [`CommonUsesTaskExt::save_asset`], but passes `settings` to the saver instead of its
+    /// default settings.
+    #[cfg(feature = "asset")]
+    fn save_asset_with_settings<'a, S: AssetSaver>(
+        &self,
+        id: impl Into<AssetId<S::Asset>>,
+        path: impl Into<AssetPath<'a>>,
+        saver: S,
+        settings: S::Settings,
+    ) -> impl Future<Output = Result<<S::OutputLoader as AssetLoader>::Settings, AssetSaveError>> + Send
+    where
+        S::Asset: Clone;
+
+    /// Wait for the [`AssetProcessor`] to finish processing every asset, including any changed
+    /// since it last finished. Resolves right away if assets aren't being processed.
+    ///
+    /// [`AssetProcessor`]: bevy_asset::processor::AssetProcessor
+    #[cfg(feature = "asset_processor")]
+    fn wait_for_processor(&self) -> impl Future<Output = ()> + Send;
 
     fn write_message<M: Message>(&self, event: M) -> WithWorld<()>;
 }
@@ -38,22 +282,166 @@ impl CommonUsesTaskExt for TaskContext {
     async fn load_asset<'a, A: Asset>(
         &self,
         path: impl Into<AssetPath<'a>> + Send + 'static,
-    ) -> Result<Handle<A>, AssetLoadError> {
-        let handle = self
-            .with_world(|world| world.resource::<AssetServer>().load(path))
-            .await;
-        let mut states = self.get_load_state(handle.id());
-        while let Some(x) = states.next().await {
-            match x {
-                RecursiveDependencyLoadState::NotLoaded => {
-                    return Err(AssetLoadError::AssetMetaReadError)
-                } //TODO work out correct error to pass
-                RecursiveDependencyLoadState::Loading => {}
-                RecursiveDependencyLoadState::Loaded => return Ok(handle),
-                RecursiveDependencyLoadState::Failed(error) => return Err(error.as_ref().clone()),
+    ) -> Result<Handle<A>, AsyncAssetError> {
+        self.load_asset_with_depth(path, LoadDepth::Recursive).await
+    }
+
+    #[cfg(feature = "asset")]
+    async fn load_asset_with_depth<'a, A: Asset>(
+        &self,
+        path: impl Into<AssetPath<'a>> + Send + 'static,
+        depth: LoadDepth,
+    ) -> Result<Handle<A>, AsyncAssetError> {
+        load_and_wait(self, depth, |assets| assets.load(path)).await
+    }
+
+    #[cfg(feature = "asset")]
+    async fn load_asset_with_settings<'a, A: Asset, S: Settings>(
+        &self,
+        path: impl Into<AssetPath<'a>> + Send + 'static,
+        settings: impl Fn(&mut S) + Send + Sync + 'static,
+    ) -> Result<Handle<A>, AsyncAssetError> {
+        load_and_wait(self, LoadDepth::Recursive, |assets| {
+            assets.load_with_settings(path, settings)
+        })
+        .await
+    }
+
+    #[cfg(feature = "asset")]
+    async fn load_asset_acquire<'a, A: Asset, G: Send + Sync + 'static>(
+        &self,
+        path: impl Into<AssetPath<'a>> + Send + 'static,
+        guard: G,
+    ) -> Result<Handle<A>, AsyncAssetError> {
+        load_and_wait(self, LoadDepth::Recursive, |assets| {
+            assets.load_acquire(path, guard)
+        })
+        .await
+    }
+
+    #[cfg(feature = "asset")]
+    async fn load_untyped<'a>(
+        &self,
+        path: impl Into<AssetPath<'a>> + Send + 'static,
+    ) -> Result<UntypedHandle, AsyncAssetError> {
+        // The loaded asset is a dependency of the `LoadedUntypedAsset` wrapping its handle
+        let loaded = load_and_wait(self, LoadDepth::Recursive, |assets| {
+            assets.load_untyped(path)
+        })
+        .await?;
+        self.with_asset(&loaded, |loaded: &LoadedUntypedAsset| loaded.handle.clone())
+            .await
+    }
+
+    #[cfg(feature = "asset")]
+    async fn load_folder<'a>(
+        &self,
+        path: impl Into<AssetPath<'a>> + Send + 'static,
+    ) -> Result<Vec<UntypedHandle>, AsyncAssetError> {
+        // The folder's handles are its dependencies, so this waits for every asset in it
+        let folder = load_and_wait(self, LoadDepth::Recursive, |assets| {
+            assets.load_folder(path)
+        })
+        .await?;
+        self.with_asset(&folder, |folder: &LoadedFolder| folder.handles.clone())
+            .await
+    }
+
+    #[cfg(feature = "asset")]
+    async fn load_folder_of<'a, A: Asset>(
+        &self,
+        path: impl Into<AssetPath<'a>> + Send + 'static,
+    ) -> Result<Vec<Handle<A>>, AsyncAssetError> {
+        let handles = self.load_folder(path).await?;
+        Ok(handles
+            .into_iter()
+            .filter_map(|handle| handle.try_typed().ok())
+            .collect())
+    }
+
+    #[cfg(feature = "asset")]
+    async fn load_asset_value<'a, A: Asset + Clone>(
+        &self,
+        path: impl Into<AssetPath<'a>> + Send + 'static,
+    ) -> Result<A, AsyncAssetError> {
+        let handle = self.load_asset::<A>(path).await?;
+        self.with_asset(&handle, A::clone).await
+    }
+
+    #[cfg(feature = "asset")]
+    fn with_asset<A: Asset, R: Send + 'static>(
+        &self,
+        id: impl Into<AssetId<A>>,
+        f: impl FnOnce(&A) -> R + Send + 'static,
+    ) -> WithWorld<Result<R, AsyncAssetError>> {
+        let id = id.into();
+        self.with_world(move |world| {
+            let asset = world.get_resource::<Assets<A>>().and_then(|a| a.get(id));
+            asset.map(f).ok_or(AsyncAssetError::Unloaded)
+        })
+    }
+
+    #[cfg(feature = "asset")]
+    fn with_asset_mut<A: Asset, R: Send + 'static>(
+        &self,
+        id: impl Into<AssetId<A>>,
+        f: impl FnOnce(&mut A) -> R + Send + 'static,
+    ) -> WithWorld<Result<R, AsyncAssetError>> {
+        let id = id.into();
+        self.with_world(move |world| {
+            let mut assets = world
+                .get_resource_mut::<Assets<A>>()
+                .ok_or(AsyncAssetError::Unloaded)?;
+            assets.get_mut(id).map(f).ok_or(AsyncAssetError::Unloaded)
+        })
+    }
+
+    #[cfg(feature = "asset")]
+    fn save_asset<'a, S: AssetSaver>(
+        &self,
+        id: impl Into<AssetId<S::Asset>>,
+        path: impl Into<AssetPath<'a>>,
+        saver: S,
+    ) -> impl Future<Output = Result<<S::OutputLoader as AssetLoader>::Settings, AssetSaveError>> + Send
+    where
+        S::Asset: Clone,
+    {
+        self.save_asset_with_settings(id, path, saver, S::Settings::default())
+    }
+
+    #[cfg(feature = "asset")]
+    fn save_asset_with_settings<'a, S: AssetSaver>(
+        &self,
+        id: impl Into<AssetId<S::Asset>>,
+        path: impl Into<AssetPath<'a>>,
+        saver: S,
+        settings: S::Settings,
+    ) -> impl Future<Output = Result<<S::OutputLoader as AssetLoader>::Settings, AssetSaveError>> + Send
+    where
+        S::Asset: Clone,
+    {
+        let id = id.into();
+        let path = path.into().into_owned();
+        let asset = self.with_world(move |world| {
+            let asset = world.get_resource::<Assets<S::Asset>>()?.get(id)?.clone();
+            Some((asset, world.resource::<AssetServer>().clone()))
+        });
+        async move {
+            let (asset, server) = asset.await.ok_or(AssetSaveError::Unloaded)?;
+            IoTaskPool::get()
+                .spawn(async move { write_asset(&server, &path, asset, &saver, &settings).await })
+                .await
+        }
+    }
+
+    #[cfg(feature = "asset_processor")]
+    fn wait_for_processor(&self) -> impl Future<Output = ()> + Send {
+        let processor = self.with_world(|world| world.get_resource::<AssetProcessor>().cloned());
+        async move {
+            if let Some(processor) = processor.await {
+                processor.data().wait_until_finished().await;
             }
         }
-        Err(AssetLoadError::AssetMetaReadError)
     }
 
     fn write_message<M: Message>(&self, message: M) -> WithWorld<()> {
@@ -62,3 +450,63 @@ impl CommonUsesTaskExt for TaskContext {
         })
     }
 }
+
+/// Run `saver` on `asset` and write the result to `path` through the server's asset sources.
+#[cfg(feature = "asset")]
+async fn write_asset<S: AssetSaver>(
+    server: &AssetServer,
+    path: &AssetPath<'static>,
+    asset: S::Asset,
+    saver: &S,
+    settings: &S::Settings,
+) -> Result<<S::OutputLoader as AssetLoader>::Settings, AssetSaveError> {
+    let source = server.get_source(path.source())?;
+    let mut writer = source.writer()?.write(path.path()).await?;
+    let asset = ErasedLoadedAsset::from(LoadedAsset::from(asset));
+    let asset = SavedAsset::from_loaded(&asset).expect("asset was erased from its own type");
+    let output = saver
+        .save(&mut writer, asset, settings)
+        .await
+        .map_err(|error| AssetSaveError::Saver(error.into()))?;
+    writer.flush().await.map_err(AssetWriterError::from)?;
+    Ok(output)
+}
+
+/// Start a load with `load` and wait for the asset to finish loading down to `depth`.
+#[cfg(feature = "asset")]
+async fn load_and_wait<A: Asset>(
+    cx: &TaskContext,
+    depth: LoadDepth,
+    load: impl FnOnce(&AssetServer) -> Handle<A> + Send + 'static,
+) -> Result<Handle<A>, AsyncAssetError> {
+    let handle = cx
+        .with_world(|world| load(world.resource::<AssetServer>()))
+        .await;
+    wait_until_loaded(cx, handle.id(), depth).await?;
+    Ok(handle)
+}
+
+/// Wait for the asset `id` to finish loading down to `depth`.
+#[cfg(feature = "asset")]
+async fn wait_until_loaded<A: Asset>(
+    cx: &TaskContext,
+    id: AssetId<A>,
+    depth: LoadDepth,
+) -> Result<(), AsyncAssetError> {
+    let poll = depth == LoadDepth::Dependencies;
+    let mut states = AssetLoadStatesStream::for_asset(cx.clone(), id, poll);
+    let id = id.untyped();
+    while let Some(states) = states.next().await {
+        match states.at_depth(depth) {
+            RecursiveDependencyLoadState::NotLoaded => return Err(AsyncAssetError::Unloaded),
+            RecursiveDependencyLoadState::Loading => {}
+            RecursiveDependencyLoadState::Loaded => return Ok(()),
+            RecursiveDependencyLoadState::Failed(error) => {
+                return Err(cx
+                    .with_world(move |world| AsyncAssetError::from_failure(world, id, error))
+                    .await)
+            }
+        }
+    }
+    Err(AsyncAssetError::SubscriptionClosed)
+}
@@ -0,0 +1,207 @@
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use bevy_ecs::{
+    entity::Entity,
+    event::Event,
+    observer::{Observer, On},
+    world::World,
+};
+use futures::{FutureExt, Stream, StreamExt};
+use tokio::sync::mpsc;
+
+use crate::{TaskContext, WithWorld};
+
+pub trait EventStreamTaskExt {
+    /// Stream every `E` triggered from now on, for as long as the stream is alive. This spawns an
+    /// [`Observer`] that forwards events to the stream, which is despawned again when the stream
+    /// is dropped. Entity events are received regardless of their target; use
+    /// [`AsyncEntity::observe`] to only receive events for a single entity.
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_mod_async::prelude::*;
+    /// # use futures::StreamExt;
+    /// #[derive(Event, Clone)]
+    /// struct Checkpoint(u32);
+    ///
+    /// # App::new()
+    /// #     .add_plugins((MinimalPlugins, AssetPlugin::default(), AsyncTasksPlugin))
+    /// #     .add_systems(Startup, |world: &mut World| {
+    /// world.spawn_task(|cx| async move {
+    ///     let mut checkpoints = cx.observe::<Checkpoint>();
+    ///     cx.with_world(|world| world.trigger(Checkpoint(1))).await;
+    ///     assert!(matches!(checkpoints.next().await, Some(Checkpoint(1))));
+    /// #     cx.write_message(AppExit::Success).await;
+    /// });
+    /// #     })
+    /// #     .run();
+    /// ```
+    ///
+    /// [`AsyncEntity::observe`]: crate::async_entity::AsyncEntity::observe
+    fn observe<E: Event + Clone>(&self) -> EventStream<E>;
+
+    /// Wait for the next `E` triggered after the observer is spawned that matches `predicate`.
+    /// The observer is despawned once a match is found. Resolves to `None` if the observer is
+    /// despawned before that.
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_mod_async::prelude::*;
+    /// #[derive(EntityEvent, Clone)]
+    /// struct Clicked {
+    ///     entity: Entity,
+    /// }
+    ///
+    /// # App::new()
+    /// #     .add_plugins((MinimalPlugins, AssetPlugin::default(), AsyncTasksPlugin))
+    /// #     .add_systems(Startup, |world: &mut World| {
+    /// world.spawn_task(|cx| async move {
+    ///     let (ok, cancel) = (cx.spawn(()).await, cx.spawn(()).await);
+    ///     let choice = cx.next_event_where(move |c: &Clicked| c.entity == ok || c.entity == cancel);
+    ///     cx.with_world(move |world| world.trigger(Clicked { entity: cancel }))
+    ///         .detach();
+    ///     assert_eq!(choice.await.unwrap().entity, cancel);
+    /// #     cx.write_message(AppExit::Success).await;
+    /// });
+    /// #     })
+    /// #     .run();
+    /// ```
+    fn next_event_where<E, F>(&self, predicate: F) -> impl Future<Output = Option<E>> + Send
+    where
+        E: Event + Clone,
+        F: Fn(&E) -> bool + Send + Sync + 'static;
+}
+
+impl EventStreamTaskExt for TaskContext {
+    fn observe<E: Event + Clone>(&self) -> EventStream<E> {
+        EventStream::new(self.clone(), None)
+    }
+
+    fn next_event_where<E, F>(&self, predicate: F) -> impl Future<Output = Option<E>> + Send
+    where
+        E: Event + Clone,
+        F: Fn(&E) -> bool + Send + Sync + 'static,
+    {
+        let mut events = self.observe::<E>();
+        async move {
+            while let Some(event) = events.next().await {
+                if predicate(&event) {
+                    return Some(event);
+                }
+            }
+            None
+        }
+    }
+}
+
+enum EventStreamState<E> {
+    Spawning(WithWorld<Option<(Entity, mpsc::UnboundedReceiver<E>)>>),
+    Observing(Entity, mpsc::UnboundedReceiver<E>),
+    Dropped,
+}
+
+/// A [`Stream`] of events received by an [`Observer`], created with
+/// [`EventStreamTaskExt::observe`] or [`AsyncEntity::observe`]. The observer is despawned when
+/// this stream is dropped. The stream ends if the observer is despawned some other way, for
+/// example because the entity it was watching was despawned.
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_mod_async::{prelude::*, AsyncWork};
+/// # #[derive(Event, Clone)]
+/// # struct Checkpoint;
+/// # let mut app = App::new();
+/// # app.add_plugins((MinimalPlugins, AssetPlugin::default(), AsyncTasksPlugin));
+/// let entities = |app: &App| app.world().entities().len();
+/// let before = entities(&app);
+/// let cx = app.world().resource::<AsyncWork>().create_task_context();
+/// let checkpoints = cx.observe::<Checkpoint>();
+/// app.update();
+/// assert_eq!(entities(&app), before + 1);
+/// drop(checkpoints);
+/// app.update();
+/// assert_eq!(entities(&app), before);
+/// ```
+///
+/// [`AsyncEntity::observe`]: crate::async_entity::AsyncEntity::observe
+pub struct EventStream<E: Send + 'static> {
+    cx: TaskContext,
+    state: EventStreamState<E>,
+}
+
+impl<E: Event + Clone> EventStream<E> {
+    /// Spawn an observer for `E` that forwards events to this stream. If `entity` is given, only
+    /// events targeting it are observed.
+    pub fn new(cx: TaskContext, entity: Option<Entity>) -> Self {
+        let fut = cx.with_world(move |world| spawn_forwarding_observer::<E>(world, entity));
+        Self {
+            cx,
+            state: EventStreamState::Spawning(fut),
+        }
+    }
+}
+
+/// Spawn the observer behind an [`EventStream`]. Returns `None` if `entity` was despawned before
+/// the observer could be spawned, since the stream would end straight away.
+fn spawn_forwarding_observer<E: Event + Clone>(
+    world: &mut World,
+    entity: Option<Entity>,
+) -> Option<(Entity, mpsc::UnboundedReceiver<E>)> {
+    if entity.is_some_and(|entity| world.get_entity(entity).is_err()) {
+        return None;
+    }
+    let (tx, rx) = mpsc::unbounded_channel();
+    let mut observer = Observer::new(move |event: On<E>| {
+        tx.send(event.event().clone()).ok();
+    });
+    if let Some(entity) = entity {
+        observer = observer.with_entity(entity);
+    }
+    Some((world.spawn(observer).id(), rx))
+}
+
+impl<E: Send + 'static> Stream for EventStream<E> {
+    type Item = E;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            match &mut self.state {
+                EventStreamState::Spawning(fut) => match fut.poll_unpin(cx) {
+                    Poll::Ready(Some((observer, rx))) => {
+                        self.state = EventStreamState::Observing(observer, rx);
+                    }
+                    Poll::Ready(None) => self.state = EventStreamState::Dropped,
+                    Poll::Pending => return Poll::Pending,
+                },
+                EventStreamState::Observing(_, rx) => return rx.poll_recv(cx),
+                EventStreamState::Dropped => return Poll::Ready(None),
+            }
+        }
+    }
+}
+
+impl<E: Send + 'static> Drop for EventStream<E> {
+    fn drop(&mut self) {
+        match std::mem::replace(&mut self.state, EventStreamState::Dropped) {
+            EventStreamState::Spawning(fut) => {
+                // Jobs run in order, so by the time this one runs the observer has been spawned
+                self.cx.try_with_world(move |world| {
+                    if let Some(Some((observer, _))) = fut.now_or_never() {
+                        world.try_despawn(observer).ok();
+                    }
+                });
+            }
+            EventStreamState::Observing(observer, _) => {
+                // The observer is already gone if the entity it was watching was despawned
+                self.cx.try_with_world(move |world| {
+                    world.try_despawn(observer).ok();
+                });
+            }
+            EventStreamState::Dropped => {}
+        }
+    }
+}
@@ -0,0 +1,262 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    future::Future,
+    marker::PhantomData,
+};
+
+use bevy_app::{App, FixedMain, FixedMainScheduleOrder, Main, MainScheduleOrder, Update};
+use bevy_ecs::{
+    resource::Resource,
+    schedule::ScheduleLabel,
+    system::ResMut,
+    world::{FromWorld, World},
+};
+use futures::FutureExt;
+use tokio::sync::oneshot;
+
+use crate::{
+    wait::{Wait, WaitId},
+    TaskContext,
+};
+
+/// Counts runs of the schedule `S` so tasks can wait on them with
+/// [`FrameTaskExt::sleep_schedule_runs`]. [`AsyncTasksPlugin`] adds this for [`Update`], and
+/// other schedules are registered on first use. Schedules that are running at the time they're
+/// first waited on (such as [`Main`]) can't be registered that way and need this plugin added up
+/// front. Adding it for a schedule that's already counted does nothing.
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_mod_async::{frames::schedule_runs_plugin, prelude::*};
+/// App::new()
+///     .add_plugins((MinimalPlugins, AssetPlugin::default(), AsyncTasksPlugin))
+///     .add_plugins(schedule_runs_plugin::<Main>);
+/// ```
+///
+/// [`AsyncTasksPlugin`]: crate::AsyncTasksPlugin
+/// [`Main`]: bevy_app::Main
+pub fn schedule_runs_plugin<S: ScheduleLabel + Default>(app: &mut App) {
+    if app.world().contains_resource::<ScheduleRuns<S>>() {
+        return;
+    }
+    app.init_resource::<ScheduleRuns<S>>();
+    app.add_systems(S::default(), count_schedule_runs::<S>);
+}
+
+pub trait FrameTaskExt {
+    /// Wait until the next run of [`Update`]. Equivalent to `sleep_frames(1)`.
+    fn next_frame(&self) -> impl Future<Output = ()>;
+
+    /// Wait for `frames` runs of [`Update`]. The count starts from the frame in which the request
+    /// is processed by [`run_async_jobs`], and the future resolves during the `frames`th
+    /// [`Update`] after that one. Waiting for zero frames resolves as soon as the request is
+    /// processed.
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_mod_async::{prelude::*, AsyncWork};
+    /// # use futures::FutureExt;
+    /// # use std::pin::pin;
+    /// # let mut app = App::new();
+    /// # app.add_plugins((MinimalPlugins, AssetPlugin::default(), AsyncTasksPlugin));
+    /// let cx = app.world().resource::<AsyncWork>().create_task_context();
+    /// let mut frames = pin!(cx.sleep_frames(3));
+    /// // The first update processes the request
+    /// app.update();
+    /// assert!(frames.as_mut().now_or_never().is_none());
+    /// app.update();
+    /// assert!(frames.as_mut().now_or_never().is_none());
+    /// app.update();
+    /// assert!(frames.as_mut().now_or_never().is_none());
+    /// app.update();
+    /// assert!(frames.as_mut().now_or_never().is_some());
+    /// ```
+    ///
+    /// [`run_async_jobs`]: crate::run_async_jobs
+    fn sleep_frames(&self, frames: u32) -> impl Future<Output = ()>;
+
+    /// Wait until the next run of the schedule `S`. Equivalent to `sleep_schedule_runs::<S>(1)`.
+    fn next_schedule_run<S: ScheduleLabel + Default>(&self) -> impl Future<Output = ()>;
+
+    /// Wait for `runs` runs of the schedule `S`, counted from when the request is processed by
+    /// [`run_async_jobs`]. The first time a schedule is waited on, a system that counts its runs is
+    /// added to it. See [`schedule_runs_plugin`] for schedules that can't be registered lazily.
+    /// Waiting on a schedule the app doesn't have panics once the request is processed:
+    ///
+    /// ```should_panic
+    /// # use bevy::{ecs::schedule::ScheduleLabel, prelude::*};
+    /// # use bevy_mod_async::{prelude::*, AsyncWork};
+    /// #[derive(ScheduleLabel, Clone, Debug, Default, PartialEq, Eq, Hash)]
+    /// struct Cutscene;
+    ///
+    /// # let mut app = App::new();
+    /// # app.add_plugins((MinimalPlugins, AssetPlugin::default(), AsyncTasksPlugin));
+    /// let cx = app.world().resource::<AsyncWork>().create_task_context();
+    /// let _wait = cx.next_schedule_run::<Cutscene>();
+    /// app.update();
+    /// ```
+    ///
+    /// [`run_async_jobs`]: crate::run_async_jobs
+    fn sleep_schedule_runs<S: ScheduleLabel + Default>(
+        &self,
+        runs: u32,
+    ) -> impl Future<Output = ()>;
+}
+
+impl FrameTaskExt for TaskContext {
+    fn next_frame(&self) -> impl Future<Output = ()> {
+        self.sleep_frames(1)
+    }
+
+    fn sleep_frames(&self, frames: u32) -> impl Future<Output = ()> {
+        self.sleep_schedule_runs::<Update>(frames)
+    }
+
+    fn next_schedule_run<S: ScheduleLabel + Default>(&self) -> impl Future<Output = ()> {
+        self.sleep_schedule_runs::<S>(1)
+    }
+
+    fn sleep_schedule_runs<S: ScheduleLabel + Default>(
+        &self,
+        runs: u32,
+    ) -> impl Future<Output = ()> {
+        let register = move |world: &mut World, id, tx| {
+            if !world.contains_resource::<ScheduleRuns<S>>() {
+                register_schedule_runs::<S>(world);
+            }
+            world.resource_mut::<ScheduleRuns<S>>().wait(id, runs, tx);
+        };
+        Wait::new(self.clone(), register, cancel_schedule_run::<S>).map(|_| ())
+    }
+}
+
+/// Stop waiting on `S`, for a [`Wait`] that was dropped before it resolved.
+fn cancel_schedule_run<S: ScheduleLabel>(world: &mut World, id: WaitId) {
+    if let Some(mut schedule_runs) = world.get_resource_mut::<ScheduleRuns<S>>() {
+        schedule_runs.cancel(id);
+    }
+}
+
+fn register_schedule_runs<S: ScheduleLabel + Default>(world: &mut World) {
+    let label = S::default().intern();
+    let registered = world.try_schedule_scope(label, |_, schedule| {
+        schedule.add_systems(count_schedule_runs::<S>);
+    });
+    if registered.is_err() {
+        if is_main_loop_schedule(world, label) {
+            panic!(
+                "Couldn't register a run counter for schedule `{label:?}` because it's currently \
+                running. Add `schedule_runs_plugin::<{label:?}>` to your app so it's registered \
+                up front"
+            );
+        } else {
+            panic!(
+                "Couldn't wait on schedule `{label:?}` because it doesn't exist. Add it to your app \
+                before waiting on it, or add `schedule_runs_plugin::<{label:?}>`"
+            );
+        }
+    }
+    world.init_resource::<ScheduleRuns<S>>();
+}
+
+/// Whether `label` is one of the schedules the app runs every frame. A schedule is taken out of
+/// [`Schedules`] while it runs, so one of these going missing means it's running right now rather
+/// than that it was never added.
+///
+/// [`Schedules`]: bevy_ecs::schedule::Schedules
+fn is_main_loop_schedule(world: &World, label: impl ScheduleLabel) -> bool {
+    let label = label.intern();
+    if label == Main.intern() || label == FixedMain.intern() {
+        return true;
+    }
+    let in_main = world
+        .get_resource::<MainScheduleOrder>()
+        .is_some_and(|order| {
+            order.labels.contains(&label) || order.startup_labels.contains(&label)
+        });
+    let in_fixed_main = world
+        .get_resource::<FixedMainScheduleOrder>()
+        .is_some_and(|order| order.labels.contains(&label));
+    in_main || in_fixed_main
+}
+
+/// Tracks how many times the schedule `S` has run, along with the tasks waiting on it. Updated by
+/// [`count_schedule_runs`].
+///
+/// Dropping a wait before it resolves removes it the next time [`run_async_jobs`] runs:
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_mod_async::{frames::ScheduleRuns, prelude::*, AsyncWork};
+/// # let mut app = App::new();
+/// # app.add_plugins((MinimalPlugins, AssetPlugin::default(), AsyncTasksPlugin));
+/// let cx = app.world().resource::<AsyncWork>().create_task_context();
+/// let forever = cx.sleep_frames(u32::MAX);
+/// app.update();
+/// assert_eq!(app.world().resource::<ScheduleRuns<Update>>().waiting(), 1);
+/// drop(forever);
+/// app.update();
+/// assert_eq!(app.world().resource::<ScheduleRuns<Update>>().waiting(), 0);
+/// ```
+///
+/// [`run_async_jobs`]: crate::run_async_jobs
+#[derive(Resource)]
+pub struct ScheduleRuns<S: ScheduleLabel> {
+    runs: u64,
+    waiting: BTreeMap<(u64, WaitId), oneshot::Sender<()>>,
+    targets: HashMap<WaitId, u64>,
+    marker: PhantomData<fn() -> S>,
+}
+
+impl<S: ScheduleLabel> FromWorld for ScheduleRuns<S> {
+    fn from_world(_: &mut World) -> Self {
+        Self {
+            runs: 0,
+            waiting: BTreeMap::new(),
+            targets: HashMap::new(),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<S: ScheduleLabel> ScheduleRuns<S> {
+    /// The number of times `S` has run since this resource was added.
+    pub fn runs(&self) -> u64 {
+        self.runs
+    }
+
+    /// The number of tasks waiting on runs of `S`.
+    pub fn waiting(&self) -> usize {
+        self.waiting.len()
+    }
+
+    fn wait(&mut self, id: WaitId, runs: u32, tx: oneshot::Sender<()>) {
+        if runs == 0 {
+            tx.send(()).ok();
+        } else {
+            let target = self.runs + u64::from(runs);
+            self.waiting.insert((target, id), tx);
+            self.targets.insert(id, target);
+        }
+    }
+
+    fn cancel(&mut self, id: WaitId) {
+        if let Some(target) = self.targets.remove(&id) {
+            self.waiting.remove(&(target, id));
+        }
+    }
+}
+
+/// Increments [`ScheduleRuns<S>`] and wakes any tasks whose wait has elapsed.
+pub fn count_schedule_runs<S: ScheduleLabel>(mut schedule_runs: ResMut<ScheduleRuns<S>>) {
+    schedule_runs.runs += 1;
+    let runs = schedule_runs.runs;
+    while let Some(entry) = schedule_runs.waiting.first_entry() {
+        if entry.key().0 > runs {
+            break;
+        }
+        let ((_, id), tx) = entry.remove_entry();
+        schedule_runs.targets.remove(&id);
+        tx.send(()).ok();
+    }
+}
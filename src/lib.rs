@@ -7,9 +7,12 @@ use std::{
 
 #[cfg(feature = "asset")]
 use async_asset::{notify_asset_events, AssetSubscriptions};
+#[cfg(feature = "asset")]
+use bevy_app::Last;
 use bevy_app::{App, Plugin, Update};
-use bevy_ecs::{resource::Resource, system::Commands, world::World};
+use bevy_ecs::{resource::Resource, schedule::IntoScheduleConfigs, system::Commands, world::World};
 use bevy_tasks::AsyncComputeTaskPool;
+use frames::{count_schedule_runs, ScheduleRuns};
 use futures::FutureExt;
 #[cfg(feature = "time")]
 use time::time_plugin;
@@ -19,33 +22,45 @@ use tokio::sync::{mpsc, oneshot};
 pub mod async_asset;
 pub mod async_entity;
 pub mod common_uses;
+pub mod event_stream;
+pub mod frames;
 pub mod message_stream;
+pub mod request;
 #[cfg(feature = "time")]
 pub mod time;
+mod wait;
 
 pub mod prelude {
+    #[cfg(feature = "asset")]
+    pub use crate::async_asset::AsyncAssetTaskExt;
     #[cfg(feature = "time")]
-    pub use crate::time::TimingTaskExt;
+    pub use crate::time::{TaskClockControls, TimingTaskExt};
     pub use crate::{
         async_entity::AsyncEntityTaskExt, common_uses::CommonUsesTaskExt,
-        message_stream::MessageStreamTaskExt, AsyncTasksPlugin, SpawnCommandExt, SpawnTaskExt,
-        TaskContext,
+        event_stream::EventStreamTaskExt, frames::FrameTaskExt,
+        message_stream::MessageStreamTaskExt, request::RequestTaskExt, AsyncTasksPlugin,
+        SpawnCommandExt, SpawnTaskExt, TaskContext,
     };
 }
 
 /// Adds [`AsyncWork`] resource to world to handle async jobs spawned from
 /// [`TaskContext::with_world`], and schedules [`run_async_jobs`] in [`Update`] to dispatch
-/// them.
+/// them. Also counts [`Update`] runs so tasks can wait on frames with
+/// [`FrameTaskExt`](frames::FrameTaskExt).
 pub struct AsyncTasksPlugin;
 
 impl Plugin for AsyncTasksPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<AsyncWork>();
-        app.add_systems(Update, run_async_jobs);
+        app.init_resource::<ScheduleRuns<Update>>();
+        app.add_systems(
+            Update,
+            (count_schedule_runs::<Update>, run_async_jobs).chain(),
+        );
         #[cfg(feature = "asset")]
         {
             app.init_resource::<AssetSubscriptions>();
-            app.add_systems(Update, notify_asset_events);
+            app.add_systems(Last, notify_asset_events);
         }
         #[cfg(feature = "time")]
         app.add_plugins(time_plugin);
@@ -199,6 +214,16 @@ impl TaskContext {
     {
         WithWorld::new(f, &self.work_queue)
     }
+
+    /// Queue `f` like a detached [`TaskContext::with_world`], but silently drop it if
+    /// [`AsyncWork`] no longer exists. Meant for cleanup from [`Drop`] impls, which may run after
+    /// the world is gone.
+    pub(crate) fn try_with_world<F>(&self, f: F)
+    where
+        F: FnOnce(&mut World) + Send + 'static,
+    {
+        self.work_queue.send(Box::new(f)).ok();
+    }
 }
 
 pub struct WithWorld<R>(oneshot::Receiver<R>);
@@ -1,51 +1,422 @@
 use std::{
-    collections::VecDeque,
+    fmt,
+    future::Future,
     pin::Pin,
     task::{Context, Poll},
 };
 
-use bevy_ecs::message::{Message, MessageCursor, Messages};
-use futures::{FutureExt, Stream};
+use bevy_app::{App, Last};
+use bevy_ecs::{
+    message::{Message, MessageCursor, Messages},
+    resource::Resource,
+    system::{Res, ResMut},
+    world::{Mut, World},
+};
+use futures::{FutureExt, Stream, StreamExt};
+use tokio::sync::mpsc;
 
 use crate::{TaskContext, WithWorld};
 
+/// Registers [`MessageSubscriptions<M>`] and schedules [`notify_message_subscribers`] for it in
+/// [`Last`]. This happens automatically the first time a task subscribes to `M`, unless [`Last`]
+/// is running at the time (for example because [`run_async_jobs`] was moved there), in which case
+/// this plugin has to be added up front. Adding it for a message type that's already registered
+/// does nothing, so existing subscriptions are kept.
+///
+/// [`run_async_jobs`]: crate::run_async_jobs
+pub fn message_stream_plugin<M: Message>(app: &mut App) {
+    if app.world().contains_resource::<MessageSubscriptions<M>>() {
+        return;
+    }
+    let messages = app.world().resource::<Messages<M>>();
+    let subscriptions = MessageSubscriptions::new(messages);
+    app.insert_resource(subscriptions);
+    app.add_systems(Last, notify_message_subscribers::<M>);
+}
+
 pub trait MessageStreamTaskExt {
+    /// Stream every `M` still in the [`Messages`] queue, followed by any written later. Same as
+    /// `message_stream_from(MessageStreamStart::Oldest)`.
     fn message_stream<M: Message + Clone + Unpin>(&self) -> impl Stream<Item = M>;
+
+    /// Stream only the `M`s written after the stream subscribes, skipping any already in the
+    /// [`Messages`] queue. Same as `message_stream_from(MessageStreamStart::Now)`.
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_mod_async::prelude::*;
+    /// # use futures::StreamExt;
+    /// # #[derive(Message, Clone)]
+    /// # struct Clicked(u32);
+    /// # App::new()
+    /// #     .add_plugins((MinimalPlugins, AssetPlugin::default(), AsyncTasksPlugin))
+    /// #     .add_message::<Clicked>()
+    /// #     .add_systems(Startup, |world: &mut World| {
+    /// world.write_message(Clicked(1));
+    /// world.spawn_task(|cx| async move {
+    ///     let mut clicks = cx.message_stream_from_now::<Clicked>();
+    ///     // Make sure the stream has subscribed before writing the next message
+    ///     cx.with_world(|_| ()).await;
+    ///     cx.write_message(Clicked(2)).detach();
+    ///     assert!(matches!(clicks.next().await, Some(Clicked(2))));
+    /// #   cx.write_message(AppExit::Success).await;
+    /// });
+    /// #     })
+    /// #     .run();
+    /// ```
+    fn message_stream_from_now<M: Message + Clone + Unpin>(&self) -> impl Stream<Item = M>;
+
+    /// Stream `M`s starting from the position given by `start`.
+    fn message_stream_from<M: Message + Clone + Unpin>(
+        &self,
+        start: MessageStreamStart,
+    ) -> impl Stream<Item = M>;
+
+    /// Like [`MessageStreamTaskExt::message_stream`], but yields [`Lagged`] when messages were
+    /// dropped before the stream could read them. See [`TryMessageStream`].
+    fn try_message_stream<M: Message + Clone + Unpin>(
+        &self,
+    ) -> impl Stream<Item = Result<M, Lagged>>;
+
+    /// Stream `map(&message)` for every `M`, starting from the oldest message in the queue. `M`
+    /// doesn't need to be [`Clone`], which makes this the way to stream messages carrying
+    /// non-[`Clone`] handles or large payloads: keep the payload in an [`Arc`] and map each
+    /// message to a clone of the [`Arc`], so every subscriber shares the same allocation.
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_mod_async::prelude::*;
+    /// # use futures::StreamExt;
+    /// # use std::sync::{mpsc, Arc, Mutex};
+    /// #[derive(Message)]
+    /// struct NavmeshBaked {
+    ///     triangles: Arc<Vec<[Vec3; 3]>>,
+    ///     // Not `Clone`, so neither is the message
+    ///     _done: Mutex<mpsc::Sender<()>>,
+    /// }
+    ///
+    /// # App::new()
+    /// #     .add_plugins((MinimalPlugins, AssetPlugin::default(), AsyncTasksPlugin))
+    /// #     .add_message::<NavmeshBaked>()
+    /// #     .add_systems(Startup, |world: &mut World| {
+    /// world.spawn_task(|cx| async move {
+    ///     let mut navmeshes = cx.message_stream_map(|m: &NavmeshBaked| m.triangles.clone());
+    ///     let triangles = navmeshes.next().await.unwrap();
+    ///     assert_eq!(triangles.len(), 1);
+    /// #     cx.write_message(AppExit::Success).await;
+    /// });
+    /// world.write_message(NavmeshBaked {
+    ///     triangles: Arc::new(vec![[Vec3::X, Vec3::Y, Vec3::Z]]),
+    ///     _done: Mutex::new(mpsc::channel().0),
+    /// });
+    /// #     })
+    /// #     .run();
+    /// ```
+    ///
+    /// [`Arc`]: std::sync::Arc
+    fn message_stream_map<M, T, F>(&self, map: F) -> impl Stream<Item = T>
+    where
+        M: Message,
+        T: Send + 'static,
+        F: Fn(&M) -> T + Send + Sync + 'static;
+
+    /// Wait for the next `M` written after this subscribes that matches `predicate`. The predicate
+    /// runs as messages are fanned out, so only the matching message is cloned and the task isn't
+    /// woken for the others. The subscription is dropped as soon as a match is found. Resolves to
+    /// `None` if the subscription is closed first, which only happens if
+    /// [`MessageSubscriptions<M>`] is removed.
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_mod_async::prelude::*;
+    /// #[derive(Message, Clone)]
+    /// struct DamageTaken {
+    ///     target: Entity,
+    ///     amount: u32,
+    /// }
+    ///
+    /// # App::new()
+    /// #     .add_plugins((MinimalPlugins, AssetPlugin::default(), AsyncTasksPlugin))
+    /// #     .add_message::<DamageTaken>()
+    /// #     .add_systems(Startup, |world: &mut World| {
+    /// world.spawn_task(|cx| async move {
+    ///     let me = cx.spawn(()).await;
+    ///     let hit = cx.next_message_where(move |d: &DamageTaken| d.target == me);
+    ///     cx.with_world(move |world| {
+    ///         world.write_message(DamageTaken { target: Entity::PLACEHOLDER, amount: 1 });
+    ///         world.write_message(DamageTaken { target: me, amount: 2 });
+    ///     })
+    ///     .detach();
+    ///     assert_eq!(hit.await.unwrap().amount, 2);
+    /// #     cx.write_message(AppExit::Success).await;
+    /// });
+    /// #     })
+    /// #     .run();
+    /// ```
+    fn next_message_where<M, F>(&self, predicate: F) -> impl Future<Output = Option<M>> + Send
+    where
+        M: Message + Clone,
+        F: Fn(&M) -> bool + Send + Sync + 'static;
 }
 
 impl MessageStreamTaskExt for TaskContext {
     fn message_stream<M: Message + Clone + Unpin>(&self) -> impl Stream<Item = M> {
-        MessageStream::<M>::new(self.clone())
+        self.message_stream_from(MessageStreamStart::Oldest)
+    }
+
+    fn message_stream_from_now<M: Message + Clone + Unpin>(&self) -> impl Stream<Item = M> {
+        self.message_stream_from(MessageStreamStart::Now)
+    }
+
+    fn message_stream_from<M: Message + Clone + Unpin>(
+        &self,
+        start: MessageStreamStart,
+    ) -> impl Stream<Item = M> {
+        MessageStream::<M>::new_from(self.clone(), start)
+    }
+
+    fn try_message_stream<M: Message + Clone + Unpin>(
+        &self,
+    ) -> impl Stream<Item = Result<M, Lagged>> {
+        TryMessageStream::new(MessageStream::<M>::new(self.clone()))
+    }
+
+    fn message_stream_map<M, T, F>(&self, map: F) -> impl Stream<Item = T>
+    where
+        M: Message,
+        T: Send + 'static,
+        F: Fn(&M) -> T + Send + Sync + 'static,
+    {
+        MessageStream::new_map(self.clone(), MessageStreamStart::Oldest, map)
+    }
+
+    fn next_message_where<M, F>(&self, predicate: F) -> impl Future<Output = Option<M>> + Send
+    where
+        M: Message + Clone,
+        F: Fn(&M) -> bool + Send + Sync + 'static,
+    {
+        let mut matches =
+            MessageStream::new_filter_map(self.clone(), MessageStreamStart::Now, move |m| {
+                predicate(m).then(|| m.clone())
+            });
+        async move { matches.next().await }
+    }
+}
+
+/// Where a [`MessageStream`] starts reading from.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum MessageStreamStart {
+    /// Start with the oldest message still in the [`Messages`] double buffer, so messages written
+    /// up to two updates before the stream subscribed are included.
+    #[default]
+    Oldest,
+    /// Start after the newest message in the queue at the time the stream subscribes. The stream
+    /// subscribes the next time [`run_async_jobs`] runs, not when it's created.
+    ///
+    /// [`run_async_jobs`]: crate::run_async_jobs
+    Now,
+}
+
+/// Fans out `M`s to every task subscribed through a [`MessageStream`]. Keeps a single
+/// [`MessageCursor`] for all subscribers, so each message is read once per frame by
+/// [`notify_message_subscribers`] no matter how many streams exist, and streams are only woken
+/// when there's something for them.
+#[derive(Resource)]
+pub struct MessageSubscriptions<M: Message> {
+    cursor: MessageCursor<M>,
+    subscribers: Vec<MessageSubscriber<M>>,
+}
+
+struct MessageSubscriber<M> {
+    /// Id of the first message this subscriber wants from [`notify_message_subscribers`]. Earlier
+    /// ones were either sent when it subscribed or deliberately skipped.
+    from: usize,
+    sink: Box<dyn MessageSink<M>>,
+}
+
+/// The sending half of a subscription. Erases the type a subscriber maps each message to, so
+/// streams over `M` and streams over values derived from `&M` can share one cursor.
+trait MessageSink<M>: Send + Sync {
+    fn send(&self, message: Result<&M, Lagged>);
+    fn is_closed(&self) -> bool;
+}
+
+/// Sends `filter_map(&message)` for each message, skipping those it returns `None` for.
+struct MappedSink<T, F> {
+    tx: mpsc::UnboundedSender<Result<T, Lagged>>,
+    filter_map: F,
+}
+
+impl<M, T, F> MessageSink<M> for MappedSink<T, F>
+where
+    T: Send + 'static,
+    F: Fn(&M) -> Option<T> + Send + Sync + 'static,
+{
+    fn send(&self, message: Result<&M, Lagged>) {
+        let item = match message {
+            Ok(message) => match (self.filter_map)(message) {
+                Some(item) => Ok(item),
+                None => return,
+            },
+            Err(lagged) => Err(lagged),
+        };
+        self.tx.send(item).ok();
+    }
+
+    fn is_closed(&self) -> bool {
+        self.tx.is_closed()
     }
 }
 
-struct MessageStreamData<M: Message> {
-    items: VecDeque<M>,
-    reader: MessageCursor<M>,
+impl<M: Message> MessageSubscriptions<M> {
+    /// Starts with a cursor at the end of the queue, since there's nobody to deliver older messages
+    /// to yet.
+    pub fn new(messages: &Messages<M>) -> Self {
+        Self {
+            cursor: messages.get_cursor_current(),
+            subscribers: Vec::new(),
+        }
+    }
+
+    /// The number of live subscriptions.
+    pub fn len(&self) -> usize {
+        self.subscribers.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.subscribers.is_empty()
+    }
 }
 
-impl<M: Message> Default for MessageStreamData<M> {
-    fn default() -> Self {
-        MessageStreamData {
-            items: Default::default(),
-            reader: Default::default(),
+impl<M: Message> MessageSubscriptions<M> {
+    /// Subscribe to `M`s starting from `start`. With [`MessageStreamStart::Oldest`], messages
+    /// still in the queue are sent immediately.
+    pub fn subscribe(
+        &mut self,
+        messages: &Messages<M>,
+        start: MessageStreamStart,
+    ) -> mpsc::UnboundedReceiver<Result<M, Lagged>>
+    where
+        M: Clone,
+    {
+        self.subscribe_map(messages, start, M::clone)
+    }
+
+    /// Like [`MessageSubscriptions::subscribe`], but each message is passed through `map` instead
+    /// of being cloned.
+    pub fn subscribe_map<T, F>(
+        &mut self,
+        messages: &Messages<M>,
+        start: MessageStreamStart,
+        map: F,
+    ) -> mpsc::UnboundedReceiver<Result<T, Lagged>>
+    where
+        T: Send + 'static,
+        F: Fn(&M) -> T + Send + Sync + 'static,
+    {
+        self.subscribe_filter_map(messages, start, move |message| Some(map(message)))
+    }
+
+    /// Like [`MessageSubscriptions::subscribe_map`], but messages `filter_map` returns `None` for
+    /// aren't sent at all, so the subscriber isn't woken for them.
+    pub fn subscribe_filter_map<T, F>(
+        &mut self,
+        messages: &Messages<M>,
+        start: MessageStreamStart,
+        filter_map: F,
+    ) -> mpsc::UnboundedReceiver<Result<T, Lagged>>
+    where
+        T: Send + 'static,
+        F: Fn(&M) -> Option<T> + Send + Sync + 'static,
+    {
+        let (tx, rx) = mpsc::unbounded_channel();
+        let sink = MappedSink { tx, filter_map };
+        if start == MessageStreamStart::Oldest {
+            for message in messages.get_cursor().read(messages) {
+                sink.send(Ok(message));
+            }
         }
+        let from = messages.oldest_message_count() + messages.len();
+        self.subscribers.push(MessageSubscriber {
+            from,
+            sink: Box::new(sink),
+        });
+        rx
     }
 }
 
-enum MessageStreamState<M: Message> {
-    HasItems(MessageStreamData<M>),
-    WaitingForTask(WithWorld<MessageStreamData<M>>),
+/// Reads any `M`s written since the last run and sends them to each subscriber in
+/// [`MessageSubscriptions<M>`]. If messages were dropped from the [`Messages`] double buffer
+/// before this system saw them, subscribers are sent a [`Lagged`] first. Subscribers whose stream
+/// has been dropped are removed.
+pub fn notify_message_subscribers<M: Message>(
+    mut subscriptions: ResMut<MessageSubscriptions<M>>,
+    messages: Res<Messages<M>>,
+) {
+    let MessageSubscriptions {
+        cursor,
+        subscribers,
+    } = &mut *subscriptions;
+    subscribers.retain(|subscriber| !subscriber.sink.is_closed());
+    if subscribers.is_empty() {
+        cursor.clear(&messages);
+        return;
+    }
+    let missed = cursor.missed_messages(&messages);
+    if missed > 0 {
+        for subscriber in subscribers.iter() {
+            subscriber.sink.send(Err(Lagged(missed)));
+        }
+    }
+    for (message, id) in cursor.read_with_id(&messages) {
+        for subscriber in subscribers.iter().filter(|s| id.id >= s.from) {
+            subscriber.sink.send(Ok(message));
+        }
+    }
 }
 
-impl<M: Message> Default for MessageStreamState<M> {
-    fn default() -> Self {
-        Self::HasItems(Default::default())
+/// Subscribe to `M` from inside a [`run_async_jobs`] job, registering [`message_stream_plugin`]
+/// first if this is the first subscription.
+///
+/// [`run_async_jobs`]: crate::run_async_jobs
+fn subscribe<M, T, F>(
+    world: &mut World,
+    start: MessageStreamStart,
+    filter_map: F,
+) -> mpsc::UnboundedReceiver<Result<T, Lagged>>
+where
+    M: Message,
+    T: Send + 'static,
+    F: Fn(&M) -> Option<T> + Send + Sync + 'static,
+{
+    if !world.contains_resource::<MessageSubscriptions<M>>() {
+        world
+            .try_schedule_scope(Last, |_, schedule| {
+                schedule.add_systems(notify_message_subscribers::<M>);
+            })
+            .unwrap_or_else(|_| {
+                panic!(
+                    "Couldn't add `notify_message_subscribers` to `Last`. If it's running when \
+                    streams are first created, add `message_stream_plugin::<{}>` to your app",
+                    std::any::type_name::<M>()
+                )
+            });
+        let subscriptions = MessageSubscriptions::new(world.resource::<Messages<M>>());
+        world.insert_resource(subscriptions);
     }
+    world.resource_scope(|world, mut subscriptions: Mut<MessageSubscriptions<M>>| {
+        subscriptions.subscribe_filter_map(world.resource::<Messages<M>>(), start, filter_map)
+    })
+}
+
+enum MessageStreamState<T> {
+    Subscribing(WithWorld<mpsc::UnboundedReceiver<Result<T, Lagged>>>),
+    Subscribed(mpsc::UnboundedReceiver<Result<T, Lagged>>),
 }
 
-/// Provides a [`Stream`] interface over a series of [`Message`]s. Asynchronously iterates
-/// over all [`Message`]s from the start of the [`Messages`] queue.
+/// Provides a [`Stream`] interface over a series of [`Message`]s. By default, asynchronously
+/// iterates over all [`Message`]s from the start of the [`Messages`] queue. See
+/// [`MessageStreamStart`] to only receive new messages instead.
 ///
 /// ```
 /// # use bevy::prelude::*;
@@ -70,52 +441,134 @@ impl<M: Message> Default for MessageStreamState<M> {
 /// #     })
 /// #     .run();
 /// ```
-pub struct MessageStream<M>
-where
-    M: Message,
-{
-    cx: TaskContext,
-    state: MessageStreamState<M>,
+///
+/// The type parameter is the item type, which for streams created with
+/// [`MessageStream::new_map`] is whatever the mapping function returns rather than a message.
+pub struct MessageStream<T> {
+    state: MessageStreamState<T>,
 }
 
-impl<M: Message> MessageStream<M> {
+impl<M: Message + Clone> MessageStream<M> {
     pub fn new(cx: TaskContext) -> Self {
+        Self::new_from(cx, MessageStreamStart::Oldest)
+    }
+
+    pub fn new_from(cx: TaskContext, start: MessageStreamStart) -> Self {
+        Self::new_map(cx, start, M::clone)
+    }
+}
+
+impl<T: Send + 'static> MessageStream<T> {
+    /// Create a stream that yields `map(&message)` for each `M`. `map` runs on the main thread as
+    /// messages are fanned out, so `M` itself never has to leave the [`World`].
+    pub fn new_map<M, F>(cx: TaskContext, start: MessageStreamStart, map: F) -> Self
+    where
+        M: Message,
+        F: Fn(&M) -> T + Send + Sync + 'static,
+    {
+        Self::new_filter_map(cx, start, move |message| Some(map(message)))
+    }
+
+    /// Like [`MessageStream::new_map`], but messages `filter_map` returns `None` for are skipped
+    /// before they're sent to the stream.
+    pub fn new_filter_map<M, F>(cx: TaskContext, start: MessageStreamStart, filter_map: F) -> Self
+    where
+        M: Message,
+        F: Fn(&M) -> Option<T> + Send + Sync + 'static,
+    {
+        let fut = cx.with_world(move |world| subscribe(world, start, filter_map));
         Self {
-            cx,
-            state: Default::default(),
+            state: MessageStreamState::Subscribing(fut),
+        }
+    }
+
+    /// Yields a [`Lagged`] before the first message received after a gap, then the messages
+    /// themselves.
+    fn poll_next_or_lagged(&mut self, cx: &mut Context<'_>) -> Poll<Option<Result<T, Lagged>>> {
+        loop {
+            match &mut self.state {
+                MessageStreamState::Subscribing(fut) => match fut.poll_unpin(cx) {
+                    Poll::Ready(rx) => self.state = MessageStreamState::Subscribed(rx),
+                    Poll::Pending => return Poll::Pending,
+                },
+                MessageStreamState::Subscribed(rx) => return rx.poll_recv(cx),
+            }
         }
     }
 }
 
-impl<M: Message + Clone + Unpin> Stream for MessageStream<M> {
-    type Item = M;
+impl<T: Send + 'static> Stream for MessageStream<T> {
+    type Item = T;
 
     fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
         loop {
-            match &mut self.state {
-                MessageStreamState::HasItems(data) => {
-                    if let Some(next) = data.items.pop_front() {
-                        return Poll::Ready(Some(next));
-                    } else {
-                        let mut reader = std::mem::take(&mut data.reader);
-                        let fut = self.cx.with_world(move |world| {
-                            let items = reader
-                                .read(world.resource::<Messages<M>>())
-                                .map(Clone::clone)
-                                .collect::<VecDeque<_>>();
-                            MessageStreamData { items, reader }
-                        });
-                        self.state = MessageStreamState::WaitingForTask(fut);
-                    }
-                }
-                MessageStreamState::WaitingForTask(fut) => {
-                    if let Poll::Ready(data) = fut.poll_unpin(cx) {
-                        self.state = MessageStreamState::HasItems(data);
-                    } else {
-                        return Poll::Pending;
-                    }
-                }
+            match std::task::ready!(self.poll_next_or_lagged(cx)) {
+                Some(Ok(message)) => return Poll::Ready(Some(message)),
+                Some(Err(Lagged(_))) => {}
+                None => return Poll::Ready(None),
             }
         }
     }
 }
+
+/// Returned by a [`TryMessageStream`] when messages were dropped from the [`Messages`] double
+/// buffer before the stream got to read them. Holds the number of messages missed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Lagged(pub usize);
+
+impl fmt::Display for Lagged {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "message stream lagged behind and missed {} messages",
+            self.0
+        )
+    }
+}
+
+impl std::error::Error for Lagged {}
+
+/// A [`MessageStream`] that reports gaps instead of silently skipping them. [`Messages`] only
+/// keeps messages for two updates, so if [`notify_message_subscribers`] doesn't run in time some
+/// will be missed. When that happens this stream yields `Err(Lagged(n))` before resuming with the
+/// oldest message that's still available.
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_mod_async::{message_stream::Lagged, prelude::*, AsyncWork};
+/// # use futures::{FutureExt, StreamExt};
+/// # #[derive(Message, Clone, Debug, PartialEq)]
+/// # struct Ping(u32);
+/// # let mut app = App::new();
+/// # app.add_plugins((MinimalPlugins, AssetPlugin::default(), AsyncTasksPlugin))
+/// #     .add_message::<Ping>();
+/// let cx = app.world().resource::<AsyncWork>().create_task_context();
+/// let mut pings = cx.try_message_stream::<Ping>();
+/// // Subscribe
+/// app.update();
+/// app.world_mut().write_message(Ping(0));
+/// app.world_mut().write_message(Ping(1));
+/// // Two message updates later, both have been dropped without being read
+/// app.world_mut().resource_mut::<Messages<Ping>>().update();
+/// app.world_mut().resource_mut::<Messages<Ping>>().update();
+/// app.world_mut().write_message(Ping(2));
+/// assert!(pings.next().now_or_never().is_none());
+/// app.update();
+/// assert_eq!(pings.next().now_or_never(), Some(Some(Err(Lagged(2)))));
+/// assert_eq!(pings.next().now_or_never(), Some(Some(Ok(Ping(2)))));
+/// ```
+pub struct TryMessageStream<T>(MessageStream<T>);
+
+impl<T> TryMessageStream<T> {
+    pub fn new(stream: MessageStream<T>) -> Self {
+        Self(stream)
+    }
+}
+
+impl<T: Send + 'static> Stream for TryMessageStream<T> {
+    type Item = Result<T, Lagged>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.0.poll_next_or_lagged(cx)
+    }
+}
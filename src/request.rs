@@ -0,0 +1,158 @@
+use std::{fmt, future::Future};
+
+use bevy_app::App;
+use bevy_ecs::{
+    resource::Resource,
+    system::{ResMut, SystemParam},
+};
+use futures::FutureExt;
+use tokio::sync::oneshot;
+
+use crate::TaskContext;
+
+/// A query that tasks can send to systems with [`RequestTaskExt::request`], and the type of the
+/// answer they get back.
+pub trait Request: Send + Sync + 'static {
+    type Response: Send + 'static;
+}
+
+/// Registers [`RequestQueue<Q>`] so systems can answer `Q` through [`Requests<Q>`]. Must be added
+/// before any system using [`Requests<Q>`] runs.
+pub fn request_plugin<Q: Request>(app: &mut App) {
+    app.init_resource::<RequestQueue<Q>>();
+}
+
+pub trait RequestTaskExt {
+    /// Send `query` to whichever system reads [`Requests<Q>`] and wait for its response. The
+    /// system can respond in the same frame or hold on to the [`Responder`] and respond later.
+    /// Resolves to [`RequestDropped`] if the request is dropped without a response, either
+    /// because its [`Responder`] was dropped or because [`RequestQueue<Q>`] was removed.
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_mod_async::{prelude::*, request::*};
+    /// struct Add(u32, u32);
+    ///
+    /// impl Request for Add {
+    ///     type Response = u32;
+    /// }
+    ///
+    /// fn answer_adds(mut requests: Requests<Add>) {
+    ///     for (responder, Add(a, b)) in requests.drain() {
+    ///         // Overflowing sums go unanswered
+    ///         if let Some(sum) = a.checked_add(b) {
+    ///             responder.respond(sum);
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// # App::new()
+    /// #     .add_plugins((MinimalPlugins, AssetPlugin::default(), AsyncTasksPlugin))
+    /// .add_plugins(request_plugin::<Add>)
+    /// .add_systems(Update, answer_adds)
+    /// #     .add_systems(Startup, |world: &mut World| {
+    /// # world.spawn_task(|cx| async move {
+    /// assert_eq!(cx.request(Add(2, 3)).await, Ok(5));
+    /// assert_eq!(cx.request(Add(u32::MAX, 1)).await, Err(RequestDropped));
+    /// #     cx.write_message(AppExit::Success).await;
+    /// # });
+    /// #     })
+    /// #     .run();
+    /// ```
+    fn request<Q: Request>(
+        &self,
+        query: Q,
+    ) -> impl Future<Output = Result<Q::Response, RequestDropped>> + Send;
+}
+
+impl RequestTaskExt for TaskContext {
+    fn request<Q: Request>(
+        &self,
+        query: Q,
+    ) -> impl Future<Output = Result<Q::Response, RequestDropped>> + Send {
+        let (tx, rx) = oneshot::channel();
+        self.with_world(move |world| {
+            world
+                .get_resource_or_init::<RequestQueue<Q>>()
+                .incoming
+                .push((Responder(tx), query));
+        })
+        .detach();
+        rx.map(|response| response.map_err(|_| RequestDropped))
+    }
+}
+
+/// Error returned by [`RequestTaskExt::request`] when the request is dropped without a response.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RequestDropped;
+
+impl fmt::Display for RequestDropped {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("request was dropped without a response")
+    }
+}
+
+impl std::error::Error for RequestDropped {}
+
+/// Answers a single request read from [`Requests<Q>`]. Dropping it without calling
+/// [`Responder::respond`] fails the request with [`RequestDropped`].
+pub struct Responder<Q: Request>(oneshot::Sender<Q::Response>);
+
+impl<Q: Request> Responder<Q> {
+    /// Send `response` to the task waiting on this request. Returns `false` if that task is no
+    /// longer waiting.
+    pub fn respond(self, response: Q::Response) -> bool {
+        self.0.send(response).is_ok()
+    }
+
+    /// Whether the task waiting on this request has been dropped.
+    pub fn is_closed(&self) -> bool {
+        self.0.is_closed()
+    }
+}
+
+/// Holds the `Q` requests that haven't been read yet. Read it through [`Requests<Q>`].
+#[derive(Resource)]
+pub struct RequestQueue<Q: Request> {
+    incoming: Vec<(Responder<Q>, Q)>,
+}
+
+impl<Q: Request> Default for RequestQueue<Q> {
+    fn default() -> Self {
+        Self {
+            incoming: Vec::new(),
+        }
+    }
+}
+
+/// Reads and answers requests sent by tasks with [`RequestTaskExt::request`]. Requires
+/// [`request_plugin`] for `Q`.
+#[derive(SystemParam)]
+pub struct Requests<'w, Q: Request> {
+    queue: ResMut<'w, RequestQueue<Q>>,
+}
+
+impl<Q: Request> Requests<'_, Q> {
+    /// Take every request that hasn't been read yet, each with the [`Responder`] that answers it.
+    /// Requests whose task has since been dropped are skipped.
+    pub fn drain(&mut self) -> impl Iterator<Item = (Responder<Q>, Q)> {
+        let mut incoming = std::mem::take(&mut self.queue.incoming);
+        incoming.retain(|(responder, _)| !responder.is_closed());
+        incoming.into_iter()
+    }
+
+    /// The number of requests that haven't been read yet, not counting those [`Requests::drain`]
+    /// would skip.
+    pub fn len(&self) -> usize {
+        self.pending().count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pending().next().is_none()
+    }
+
+    fn pending(&self) -> impl Iterator<Item = &(Responder<Q>, Q)> {
+        let incoming = self.queue.incoming.iter();
+        incoming.filter(|(responder, _)| !responder.is_closed())
+    }
+}
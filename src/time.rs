@@ -1,91 +1,876 @@
-use std::{future::Future, time::Duration};
-
-use bevy_app::{App, Update};
-use bevy_ecs::{
-    component::Component,
-    entity::Entity,
-    system::{Commands, Query, Res},
-    world::World,
-};
-use bevy_time::Time;
-use futures::TryFutureExt;
-use tokio::sync::oneshot;
-
-use crate::TaskContext;
-
-pub fn time_plugin(app: &mut App) {
-    app.add_systems(Update, (advance_timeout_after, advance_timeout_at));
-}
-
-pub trait TimingTaskExt {
-    fn sleep(&self, duration: Duration) -> impl Future<Output = ()>;
-    fn sleep_until(&self, duration: Duration) -> impl Future<Output = ()>;
-}
-
-impl TimingTaskExt for TaskContext {
-    fn sleep(&self, duration: Duration) -> impl Future<Output = ()> {
-        let (tx, rx) = oneshot::channel();
-        self.with_world(move |world| {
-            world.spawn(TimeoutAfter(duration, tx));
-        })
-        .detach();
-        rx.unwrap_or_else(|_| ())
-    }
-
-    fn sleep_until(&self, elapsed_since_startup: Duration) -> impl Future<Output = ()> {
-        let (tx, rx) = oneshot::channel();
-        self.with_world(move |world| {
-            world.spawn(TimeoutAt(elapsed_since_startup, tx));
-        })
-        .detach();
-        rx.unwrap_or_else(|_| ())
-    }
-}
-
-#[derive(Component)]
-pub struct TimeoutAfter(Duration, oneshot::Sender<()>);
-
-#[derive(Component)]
-pub struct TimeoutAt(Duration, oneshot::Sender<()>);
-
-pub fn advance_timeout_after(
-    mut timeouts: Query<(Entity, &mut TimeoutAfter)>,
-    time: Res<Time>,
-    mut commands: Commands,
-) {
-    for (e, mut timeout) in &mut timeouts {
-        if let Some(new_timeout) = timeout.0.checked_sub(time.delta()) {
-            timeout.0 = new_timeout;
-        } else {
-            commands.queue(move |world: &mut World| {
-                let Ok(mut e) = world.get_entity_mut(e) else {
-                    return;
-                };
-                if let Some(timeout) = e.take::<TimeoutAfter>() {
-                    timeout.1.send(()).ok();
-                }
-                e.despawn();
-            });
-        }
-    }
-}
-
-pub fn advance_timeout_at(
-    timeouts: Query<(Entity, &TimeoutAt)>,
-    time: Res<Time>,
-    mut commands: Commands,
-) {
-    for (e, timeout) in &timeouts {
-        if time.elapsed() >= timeout.0 {
-            commands.queue(move |world: &mut World| {
-                let Ok(mut e) = world.get_entity_mut(e) else {
-                    return;
-                };
-                if let Some(timeout) = e.take::<TimeoutAt>() {
-                    timeout.1.send(()).ok();
-                }
-                e.despawn();
-            });
-        }
-    }
-}
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt,
+    future::Future,
+    marker::PhantomData,
+    ops::{Add, AddAssign, Sub, SubAssign},
+    pin::{pin, Pin},
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use bevy_app::{App, First, Update};
+use bevy_ecs::{
+    resource::Resource,
+    schedule::IntoScheduleConfigs,
+    system::{Res, ResMut},
+    world::World,
+};
+use bevy_time::{Fixed, Real, Time, TimeSystems, Virtual};
+use futures::{
+    future::{select, Either},
+    FutureExt, Stream,
+};
+use log::warn;
+use tokio::sync::oneshot;
+
+use crate::{
+    wait::{Wait, WaitId},
+    TaskContext, WithWorld,
+};
+
+pub fn time_plugin(app: &mut App) {
+    app.init_resource::<Timers>();
+    app.init_resource::<Timers<Real>>();
+    app.init_resource::<Timers<Virtual>>();
+    app.init_resource::<Timers<Fixed>>();
+    app.add_systems(
+        Update,
+        (
+            wake_timers::<()>,
+            wake_timers::<Real>,
+            wake_timers::<Virtual>,
+            wake_timers::<Fixed>,
+        ),
+    );
+}
+
+/// Adds a [`TaskClock<M>`] that tasks can sleep on with
+/// [`TimingTaskExt::sleep_with_clock::<TaskClock<M>>`](TimingTaskExt::sleep_with_clock). The clock
+/// follows [`Time<Real>`], scaled by its relative speed, and can be controlled from systems
+/// through `ResMut<Time<TaskClock<M>>>` with the methods on [`TaskClockControls`]:
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_mod_async::{prelude::*, time::{task_clock_plugin, TaskClock}, AsyncWork};
+/// # use futures::FutureExt;
+/// # use std::{pin::pin, time::Duration};
+/// struct Cutscene;
+///
+/// # let mut app = App::new();
+/// # app.add_plugins((MinimalPlugins, AssetPlugin::default(), AsyncTasksPlugin));
+/// app.add_plugins(task_clock_plugin::<Cutscene>);
+/// app.world_mut()
+///     .resource_mut::<Time<TaskClock<Cutscene>>>()
+///     .pause();
+///
+/// let cx = app.world().resource::<AsyncWork>().create_task_context();
+/// let mut line = pin!(cx.sleep_with_clock::<TaskClock<Cutscene>>(Duration::from_nanos(1)));
+/// for _ in 0..3 {
+///     std::thread::sleep(Duration::from_millis(1));
+///     app.update();
+/// }
+/// // Still paused, so no time has passed on the cutscene clock
+/// assert!(line.as_mut().now_or_never().is_none());
+///
+/// app.world_mut()
+///     .resource_mut::<Time<TaskClock<Cutscene>>>()
+///     .unpause();
+/// std::thread::sleep(Duration::from_millis(1));
+/// app.update();
+/// assert!(line.as_mut().now_or_never().is_some());
+/// ```
+pub fn task_clock_plugin<M: Send + Sync + 'static>(app: &mut App) {
+    app.init_resource::<Time<TaskClock<M>>>();
+    app.init_resource::<Timers<TaskClock<M>>>();
+    app.add_systems(First, advance_task_clock::<M>.after(TimeSystems));
+    app.add_systems(Update, wake_timers::<TaskClock<M>>);
+}
+
+/// A clock context for groups of tasks that need their own notion of time, like a cutscene that
+/// can be paused, slowed down or fast-forwarded without touching [`Time<Virtual>`]. The marker
+/// `M` tells clocks apart. Add one with [`task_clock_plugin`].
+pub struct TaskClock<M> {
+    paused: bool,
+    relative_speed: f64,
+    marker: PhantomData<fn() -> M>,
+}
+
+impl<M> Default for TaskClock<M> {
+    fn default() -> Self {
+        Self {
+            paused: false,
+            relative_speed: 1.0,
+            marker: PhantomData,
+        }
+    }
+}
+
+/// Controls for a [`Time<TaskClock<M>>`], mirroring the ones Bevy provides on [`Time<Virtual>`].
+pub trait TaskClockControls {
+    /// Stop the clock. Tasks sleeping on it stay asleep until it's resumed.
+    fn pause(&mut self);
+
+    /// Resume the clock if paused.
+    fn unpause(&mut self);
+
+    fn is_paused(&self) -> bool;
+
+    /// How fast the clock advances relative to [`Time<Real>`].
+    fn relative_speed(&self) -> f64;
+
+    /// Set how fast the clock advances relative to [`Time<Real>`]. Values above `1.0`
+    /// fast-forward, values below slow it down. Panics if `ratio` is negative or not finite.
+    fn set_relative_speed(&mut self, ratio: f64);
+}
+
+impl<M: Send + Sync + 'static> TaskClockControls for Time<TaskClock<M>> {
+    fn pause(&mut self) {
+        self.context_mut().paused = true;
+    }
+
+    fn unpause(&mut self) {
+        self.context_mut().paused = false;
+    }
+
+    fn is_paused(&self) -> bool {
+        self.context().paused
+    }
+
+    fn relative_speed(&self) -> f64 {
+        self.context().relative_speed
+    }
+
+    fn set_relative_speed(&mut self, ratio: f64) {
+        assert!(ratio.is_finite(), "tried to go infinitely fast");
+        assert!(ratio >= 0.0, "tried to go back in time");
+        self.context_mut().relative_speed = ratio;
+    }
+}
+
+/// Advances [`Time<TaskClock<M>>`] by this frame's [`Time<Real>`] delta, scaled by the clock's
+/// relative speed.
+pub fn advance_task_clock<M: Send + Sync + 'static>(
+    mut clock: ResMut<Time<TaskClock<M>>>,
+    real: Res<Time<Real>>,
+) {
+    let delta = if clock.is_paused() {
+        Duration::ZERO
+    } else {
+        real.delta().mul_f64(clock.relative_speed())
+    };
+    clock.advance_by(delta);
+}
+
+/// A clock sleeps can be measured against: the default clock `()`, [`Real`], [`Virtual`],
+/// [`Fixed`] and any [`TaskClock`]. This trait is sealed, since a clock needs the [`Time<C>`] and
+/// [`Timers<C>`] resources that [`AsyncTasksPlugin`] and [`task_clock_plugin`] add:
+///
+/// ```compile_fail
+/// # use bevy_mod_async::prelude::*;
+/// # use std::time::Duration;
+/// # fn sleep(cx: TaskContext) {
+/// let _ = cx.sleep_with_clock::<u32>(Duration::from_secs(1));
+/// # }
+/// ```
+///
+/// Sleeping on a clock whose [`Time<C>`] doesn't exist, like a [`TaskClock`] whose plugin wasn't
+/// added, logs a warning naming the missing plugin. Its timers stay pending until the plugin is
+/// added, counting from the moment the clock starts:
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_mod_async::{prelude::*, time::{task_clock_plugin, TaskClock}, AsyncWork};
+/// # use futures::FutureExt;
+/// # use std::{pin::pin, time::Duration};
+/// struct Cutscene;
+///
+/// # let mut app = App::new();
+/// # app.add_plugins((MinimalPlugins, AssetPlugin::default(), AsyncTasksPlugin));
+/// let cx = app.world().resource::<AsyncWork>().create_task_context();
+/// let mut line = pin!(cx.sleep_with_clock::<TaskClock<Cutscene>>(Duration::ZERO));
+/// app.update();
+/// app.update();
+/// assert!(line.as_mut().now_or_never().is_none());
+///
+/// app.add_plugins(task_clock_plugin::<Cutscene>);
+/// app.update();
+/// assert!(line.as_mut().now_or_never().is_some());
+/// ```
+///
+/// [`AsyncTasksPlugin`]: crate::AsyncTasksPlugin
+pub trait Clock: sealed::Sealed + Default + Send + Sync + 'static {}
+
+impl Clock for () {}
+impl Clock for Real {}
+impl Clock for Virtual {}
+impl Clock for Fixed {}
+impl<M: Send + Sync + 'static> Clock for TaskClock<M> {}
+
+mod sealed {
+    use bevy_time::{Fixed, Real, Virtual};
+
+    use super::TaskClock;
+
+    pub trait Sealed {
+        /// The plugin that adds this clock's [`Time`](bevy_time::Time) resource.
+        fn plugin() -> String {
+            "TimePlugin".to_string()
+        }
+    }
+
+    impl Sealed for () {}
+    impl Sealed for Real {}
+    impl Sealed for Virtual {}
+    impl Sealed for Fixed {}
+    impl<M> Sealed for TaskClock<M> {
+        fn plugin() -> String {
+            format!("task_clock_plugin::<{}>", std::any::type_name::<M>())
+        }
+    }
+}
+
+/// The current [`Time::elapsed`] of the clock `C`, or `None` if it isn't registered.
+fn elapsed<C: Clock>(world: &World) -> Option<Duration> {
+    world.get_resource::<Time<C>>().map(Time::elapsed)
+}
+
+pub trait TimingTaskExt {
+    /// Sleep for `duration` as measured by the default [`Time`] clock. Since tasks are resumed
+    /// from [`Update`], this is [`Time<Virtual>`] unless [`run_async_jobs`] has been moved.
+    ///
+    /// [`run_async_jobs`]: crate::run_async_jobs
+    fn sleep(&self, duration: Duration) -> impl Future<Output = ()>;
+
+    /// Sleep until the default [`Time`] clock reaches `instant`. If `instant` has already passed
+    /// by the time the request is processed, this resolves right away without registering a
+    /// timer.
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_mod_async::prelude::*;
+    /// # use std::time::Duration;
+    /// # App::new()
+    /// #     .add_plugins((MinimalPlugins, AssetPlugin::default(), AsyncTasksPlugin))
+    /// #     .add_systems(Startup, |world: &mut World| {
+    /// world.spawn_task(|cx| async move {
+    ///     let respawn_at = cx.now().await + Duration::from_millis(10);
+    ///     cx.sleep_until(respawn_at).await;
+    ///     assert!(cx.now().await >= respawn_at);
+    /// #     cx.write_message(AppExit::Success).await;
+    /// });
+    /// #     })
+    /// #     .run();
+    /// ```
+    fn sleep_until(&self, instant: GameInstant) -> impl Future<Output = ()>;
+
+    /// Sleep for `duration` of wall-clock time. Keeps running while the game is paused, which
+    /// makes it a good fit for UI animations and network timeouts.
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_mod_async::{prelude::*, AsyncWork};
+    /// # use futures::FutureExt;
+    /// # use std::{pin::pin, time::Duration};
+    /// # let mut app = App::new();
+    /// # app.add_plugins((MinimalPlugins, AssetPlugin::default(), AsyncTasksPlugin));
+    /// app.world_mut().resource_mut::<Time<Virtual>>().pause();
+    /// let cx = app.world().resource::<AsyncWork>().create_task_context();
+    /// let mut fade_in = pin!(cx.sleep_real(Duration::from_millis(1)));
+    /// let mut respawn = pin!(cx.sleep_virtual(Duration::from_millis(1)));
+    /// for _ in 0..3 {
+    ///     std::thread::sleep(Duration::from_millis(2));
+    ///     app.update();
+    /// }
+    /// assert!(fade_in.as_mut().now_or_never().is_some());
+    /// assert!(respawn.as_mut().now_or_never().is_none());
+    /// ```
+    fn sleep_real(&self, duration: Duration) -> impl Future<Output = ()>;
+
+    /// Sleep for `duration` of game time. Stops while [`Time<Virtual>`] is paused and follows its
+    /// relative speed.
+    fn sleep_virtual(&self, duration: Duration) -> impl Future<Output = ()>;
+
+    /// Sleep for `duration` of fixed timestep time, as accumulated by [`Time<Fixed>`].
+    fn sleep_fixed(&self, duration: Duration) -> impl Future<Output = ()>;
+
+    /// Sleep for `duration` as measured by [`Time<C>`].
+    fn sleep_with_clock<C: Clock>(&self, duration: Duration) -> impl Future<Output = ()>;
+
+    /// Sleep until the clock [`Time<C>`] reaches `instant`.
+    fn sleep_until_with_clock<C: Clock>(&self, instant: GameInstant) -> impl Future<Output = ()>;
+
+    /// The current [`GameInstant`] on the default [`Time`] clock, for computing deadlines.
+    fn now(&self) -> WithWorld<GameInstant>;
+
+    /// The current [`GameInstant`] on the clock [`Time<C>`].
+    fn now_with_clock<C: Clock>(&self) -> WithWorld<GameInstant>;
+
+    /// Create an [`Interval`] that ticks every `period` on the default [`Time`] clock.
+    fn interval(&self, period: Duration) -> Interval;
+
+    /// Create an [`Interval`] that ticks every `period` as measured by [`Time<C>`].
+    fn interval_with_clock<C: Clock>(&self, period: Duration) -> Interval<C>;
+
+    /// Run `future` until it completes or `duration` of game time passes, whichever comes first.
+    /// On expiry `future` is dropped and [`Elapsed`] is returned. Uses the default [`Time`]
+    /// clock, so the countdown stops while the game is paused.
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_mod_async::prelude::*;
+    /// # use std::time::Duration;
+    /// # App::new()
+    /// #     .add_plugins((MinimalPlugins, AssetPlugin::default(), AsyncTasksPlugin))
+    /// #     .add_systems(Startup, |world: &mut World| {
+    /// world.spawn_task(|cx| async move {
+    ///     let never = std::future::pending::<()>();
+    ///     assert!(cx.timeout(Duration::from_millis(10), never).await.is_err());
+    ///     let ready = std::future::ready(5);
+    ///     assert_eq!(cx.timeout(Duration::from_secs(60), ready).await, Ok(5));
+    /// #     cx.write_message(AppExit::Success).await;
+    /// });
+    /// #     })
+    /// #     .run();
+    /// ```
+    ///
+    /// If `future` finishes in the same frame the time runs out, its output wins:
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_mod_async::{prelude::*, AsyncWork};
+    /// # use futures::FutureExt;
+    /// # use std::{pin::pin, time::Duration};
+    /// # let mut app = App::new();
+    /// # app.add_plugins((MinimalPlugins, AssetPlugin::default(), AsyncTasksPlugin));
+    /// let cx = app.world().resource::<AsyncWork>().create_task_context();
+    /// let mut answer = pin!(cx.timeout(Duration::ZERO, cx.with_world(|_| 5)));
+    /// app.update();
+    /// assert_eq!(answer.as_mut().now_or_never(), Some(Ok(5)));
+    /// ```
+    fn timeout<F: Future>(
+        &self,
+        duration: Duration,
+        future: F,
+    ) -> impl Future<Output = Result<F::Output, Elapsed>>;
+
+    /// Run `future` until it completes or the default [`Time`] clock reaches `instant`,
+    /// whichever comes first. See [`TimingTaskExt::timeout`].
+    fn deadline<F: Future>(
+        &self,
+        instant: GameInstant,
+        future: F,
+    ) -> impl Future<Output = Result<F::Output, Elapsed>>;
+
+    /// Like [`TimingTaskExt::timeout`], measuring `duration` with [`Time<C>`].
+    fn timeout_with_clock<C: Clock, F: Future>(
+        &self,
+        duration: Duration,
+        future: F,
+    ) -> impl Future<Output = Result<F::Output, Elapsed>>;
+
+    /// Like [`TimingTaskExt::deadline`], comparing `instant` against [`Time<C>`].
+    fn deadline_with_clock<C: Clock, F: Future>(
+        &self,
+        instant: GameInstant,
+        future: F,
+    ) -> impl Future<Output = Result<F::Output, Elapsed>>;
+}
+
+impl TimingTaskExt for TaskContext {
+    fn sleep(&self, duration: Duration) -> impl Future<Output = ()> {
+        self.sleep_with_clock::<()>(duration)
+    }
+
+    fn sleep_until(&self, instant: GameInstant) -> impl Future<Output = ()> {
+        self.sleep_until_with_clock::<()>(instant)
+    }
+
+    fn sleep_real(&self, duration: Duration) -> impl Future<Output = ()> {
+        self.sleep_with_clock::<Real>(duration)
+    }
+
+    fn sleep_virtual(&self, duration: Duration) -> impl Future<Output = ()> {
+        self.sleep_with_clock::<Virtual>(duration)
+    }
+
+    fn sleep_fixed(&self, duration: Duration) -> impl Future<Output = ()> {
+        self.sleep_with_clock::<Fixed>(duration)
+    }
+
+    fn sleep_with_clock<C: Clock>(&self, duration: Duration) -> impl Future<Output = ()> {
+        Timer::<C>::after(self.clone(), duration).map(|_| ())
+    }
+
+    fn sleep_until_with_clock<C: Clock>(&self, instant: GameInstant) -> impl Future<Output = ()> {
+        Timer::<C>::at(self.clone(), instant.0).map(|_| ())
+    }
+
+    fn now(&self) -> WithWorld<GameInstant> {
+        self.now_with_clock::<()>()
+    }
+
+    fn now_with_clock<C: Clock>(&self) -> WithWorld<GameInstant> {
+        self.with_world(|world| GameInstant(elapsed::<C>(world).unwrap_or_default()))
+    }
+
+    fn interval(&self, period: Duration) -> Interval {
+        self.interval_with_clock(period)
+    }
+
+    fn interval_with_clock<C: Clock>(&self, period: Duration) -> Interval<C> {
+        Interval::new(self.clone(), period)
+    }
+
+    fn timeout<F: Future>(
+        &self,
+        duration: Duration,
+        future: F,
+    ) -> impl Future<Output = Result<F::Output, Elapsed>> {
+        self.timeout_with_clock::<(), F>(duration, future)
+    }
+
+    fn deadline<F: Future>(
+        &self,
+        instant: GameInstant,
+        future: F,
+    ) -> impl Future<Output = Result<F::Output, Elapsed>> {
+        self.deadline_with_clock::<(), F>(instant, future)
+    }
+
+    fn timeout_with_clock<C: Clock, F: Future>(
+        &self,
+        duration: Duration,
+        future: F,
+    ) -> impl Future<Output = Result<F::Output, Elapsed>> {
+        race(Timer::<C>::after(self.clone(), duration), future)
+    }
+
+    fn deadline_with_clock<C: Clock, F: Future>(
+        &self,
+        instant: GameInstant,
+        future: F,
+    ) -> impl Future<Output = Result<F::Output, Elapsed>> {
+        race(Timer::<C>::at(self.clone(), instant.0), future)
+    }
+}
+
+/// Drive `future` against `timer`. Whichever loses is dropped, which cancels the timer if the
+/// future wins. `future` is polled first, so its output isn't thrown away if both are ready at
+/// once.
+async fn race<C: Clock, F: Future>(timer: Timer<C>, future: F) -> Result<F::Output, Elapsed> {
+    match select(pin!(future), timer).await {
+        Either::Left((output, _)) => Ok(output),
+        Either::Right(_) => Err(Elapsed(())),
+    }
+}
+
+/// Error returned by [`TimingTaskExt::timeout`] and [`TimingTaskExt::deadline`] when the time
+/// limit is reached before the future completes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Elapsed(());
+
+impl fmt::Display for Elapsed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("deadline has elapsed")
+    }
+}
+
+impl std::error::Error for Elapsed {}
+
+/// A timer entry in [`Timers<C>`] that's removed again if this future is dropped before it fires.
+/// Resolves with the elapsed time the timer fired at, or an error if its [`Timers<C>`] went away.
+struct Timer<C: Clock>(Wait<Duration>, PhantomData<fn() -> C>);
+
+impl<C: Clock> Timer<C> {
+    fn after(cx: TaskContext, duration: Duration) -> Self {
+        Self::new(cx, move |now| now + duration)
+    }
+
+    fn at(cx: TaskContext, deadline: Duration) -> Self {
+        Self::new(cx, move |_| deadline)
+    }
+
+    /// Create a timer whose deadline is computed from the clock's current elapsed time.
+    fn new(cx: TaskContext, deadline: impl FnOnce(Duration) -> Duration + Send + 'static) -> Self {
+        let register = move |world: &mut World, id, tx| insert_timer::<C>(world, id, deadline, tx);
+        Self(Wait::new(cx, register, remove_timer::<C>), PhantomData)
+    }
+}
+
+impl<C: Clock> Future for Timer<C> {
+    type Output = Result<Duration, oneshot::error::RecvError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.0.poll_unpin(cx)
+    }
+}
+
+/// Cancel a timer whose future was dropped before it fired.
+fn remove_timer<C: Clock>(world: &mut World, id: WaitId) {
+    if let Some(mut timers) = world.get_resource_mut::<Timers<C>>() {
+        timers.remove(id);
+    }
+}
+
+/// Insert a timer into [`Timers<C>`], or fire it straight away if its deadline has already
+/// passed. If the clock isn't registered, a warning is logged and the timer waits in a fresh
+/// [`Timers<C>`] for the clock to be added.
+fn insert_timer<C: Clock>(
+    world: &mut World,
+    id: WaitId,
+    deadline: impl FnOnce(Duration) -> Duration,
+    tx: oneshot::Sender<Duration>,
+) {
+    let Some(now) = elapsed::<C>(world) else {
+        warn!(
+            "Tried to sleep on `Time<{}>`, which doesn't exist. The sleep won't finish until \
+            `{}` is added to the app",
+            std::any::type_name::<C>(),
+            C::plugin()
+        );
+        let deadline = deadline(Duration::ZERO);
+        world
+            .get_resource_or_init::<Timers<C>>()
+            .insert(id, deadline, tx);
+        return;
+    };
+    let deadline = deadline(now);
+    if deadline <= now {
+        tx.send(now).ok();
+    } else if let Some(mut timers) = world.get_resource_mut::<Timers<C>>() {
+        timers.insert(id, deadline, tx);
+    }
+}
+
+/// A point in time on one of the [`Time`] clocks, measured as [`Time::elapsed`] since startup.
+/// Get the current one with [`TimingTaskExt::now`] and offset it with a [`Duration`] to build
+/// deadlines for [`TimingTaskExt::sleep_until`] and [`TimingTaskExt::deadline`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct GameInstant(Duration);
+
+impl GameInstant {
+    /// The instant at which [`Time::elapsed`] equals `elapsed_since_startup`.
+    pub const fn from_elapsed(elapsed_since_startup: Duration) -> Self {
+        Self(elapsed_since_startup)
+    }
+
+    /// How long after startup this instant is.
+    pub const fn elapsed_since_startup(&self) -> Duration {
+        self.0
+    }
+
+    /// The amount of time from `earlier` to this instant, or zero if `earlier` is later.
+    pub fn duration_since(&self, earlier: GameInstant) -> Duration {
+        self.0.saturating_sub(earlier.0)
+    }
+
+    pub fn checked_add(&self, duration: Duration) -> Option<GameInstant> {
+        self.0.checked_add(duration).map(Self)
+    }
+
+    pub fn checked_sub(&self, duration: Duration) -> Option<GameInstant> {
+        self.0.checked_sub(duration).map(Self)
+    }
+}
+
+impl Add<Duration> for GameInstant {
+    type Output = GameInstant;
+
+    fn add(self, rhs: Duration) -> Self::Output {
+        Self(self.0 + rhs)
+    }
+}
+
+impl AddAssign<Duration> for GameInstant {
+    fn add_assign(&mut self, rhs: Duration) {
+        self.0 += rhs;
+    }
+}
+
+impl Sub<Duration> for GameInstant {
+    type Output = GameInstant;
+
+    fn sub(self, rhs: Duration) -> Self::Output {
+        Self(self.0 - rhs)
+    }
+}
+
+impl SubAssign<Duration> for GameInstant {
+    fn sub_assign(&mut self, rhs: Duration) {
+        self.0 -= rhs;
+    }
+}
+
+impl Sub<GameInstant> for GameInstant {
+    type Output = Duration;
+
+    /// Saturates to zero like [`GameInstant::duration_since`].
+    fn sub(self, rhs: GameInstant) -> Self::Output {
+        self.duration_since(rhs)
+    }
+}
+
+/// How an [`Interval`] catches up when ticks are missed, either because frames are longer than
+/// its period or because the task wasn't polling it. Mirrors [`tokio::time::MissedTickBehavior`].
+///
+/// [`tokio::time::MissedTickBehavior`]: https://docs.rs/tokio/latest/tokio/time/enum.MissedTickBehavior.html
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum MissedTickBehavior {
+    /// Yield every missed tick back to back until the interval has caught up with its original
+    /// schedule.
+    #[default]
+    Burst,
+    /// Drop the missed ticks and resume on the next multiple of the period from the original
+    /// schedule.
+    Skip,
+    /// Drop the missed ticks and restart the schedule one period after the late tick.
+    Delay,
+}
+
+/// A single tick yielded by an [`Interval`]. A tick that's late by more than a period reports the
+/// ticks it caused to be missed, and with [`MissedTickBehavior::Burst`] the catch-up ticks that
+/// follow it don't report them again:
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_mod_async::{prelude::*, time::{task_clock_plugin, TaskClock}, AsyncWork};
+/// # use futures::{FutureExt, StreamExt};
+/// # use std::time::Duration;
+/// struct Replay;
+///
+/// # let mut app = App::new();
+/// # app.add_plugins((MinimalPlugins, AssetPlugin::default(), AsyncTasksPlugin));
+/// app.add_plugins(task_clock_plugin::<Replay>);
+/// app.world_mut()
+///     .resource_mut::<Time<TaskClock<Replay>>>()
+///     .pause();
+///
+/// let cx = app.world().resource::<AsyncWork>().create_task_context();
+/// let mut ticks = cx.interval_with_clock::<TaskClock<Replay>>(Duration::from_millis(10));
+/// app.update();
+/// assert!(ticks.next().now_or_never().is_none());
+/// app.update();
+/// // One frame 25ms late
+/// app.world_mut()
+///     .resource_mut::<Time<TaskClock<Replay>>>()
+///     .advance_by(Duration::from_millis(35));
+/// app.update();
+///
+/// let mut next_missed = || ticks.next().now_or_never().flatten().unwrap().missed;
+/// assert_eq!([next_missed(), next_missed(), next_missed()], [2, 0, 0]);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Tick {
+    /// The instant this tick was scheduled for.
+    pub scheduled: GameInstant,
+    /// The instant the tick was observed at. Never earlier than `scheduled`.
+    pub fired: GameInstant,
+    /// How many ticks were missed because this one was late, counted in whole periods. With
+    /// [`MissedTickBehavior::Burst`] those ticks are yielded right after this one with a `missed`
+    /// of zero, otherwise they're dropped. Either way each missed tick is only counted once, so
+    /// summing `missed` gives the total number of late ticks.
+    pub missed: u32,
+}
+
+enum IntervalState<C: Clock> {
+    Starting(WithWorld<Duration>),
+    Waiting(Duration, Timer<C>),
+    Behind(Duration, Duration),
+}
+
+/// A [`Stream`] of [`Tick`]s spaced `period` apart on the clock `C`, created with
+/// [`TimingTaskExt::interval`]. The first tick fires one period after the interval is created.
+/// Deadlines are computed from the original schedule rather than from when each tick was
+/// processed, so unlike sleeping in a loop the interval doesn't drift:
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_mod_async::prelude::*;
+/// # use futures::StreamExt;
+/// # use std::time::Duration;
+/// # App::new()
+/// #     .add_plugins((MinimalPlugins, AssetPlugin::default(), AsyncTasksPlugin))
+/// #     .add_systems(Startup, |world: &mut World| {
+/// world.spawn_task(|cx| async move {
+///     let mut ticks = cx.interval(Duration::from_millis(10));
+///     let first = ticks.next().await.unwrap();
+///     let second = ticks.next().await.unwrap();
+///     assert_eq!(second.scheduled - first.scheduled, Duration::from_millis(10));
+/// #     cx.write_message(AppExit::Success).await;
+/// });
+/// #     })
+/// #     .run();
+/// ```
+pub struct Interval<C: Clock = ()> {
+    cx: TaskContext,
+    period: Duration,
+    missed_tick_behavior: MissedTickBehavior,
+    state: IntervalState<C>,
+    marker: PhantomData<fn() -> C>,
+}
+
+impl<C: Clock> Interval<C> {
+    /// Create a new interval. Panics if `period` is zero.
+    pub fn new(cx: TaskContext, period: Duration) -> Self {
+        assert!(!period.is_zero(), "`Interval` period must be non-zero");
+        let fut = cx.with_world(|world| elapsed::<C>(world).unwrap_or_default());
+        Self {
+            cx,
+            period,
+            missed_tick_behavior: MissedTickBehavior::default(),
+            state: IntervalState::Starting(fut),
+            marker: PhantomData,
+        }
+    }
+
+    pub fn period(&self) -> Duration {
+        self.period
+    }
+
+    pub fn missed_tick_behavior(&self) -> MissedTickBehavior {
+        self.missed_tick_behavior
+    }
+
+    pub fn set_missed_tick_behavior(&mut self, behavior: MissedTickBehavior) {
+        self.missed_tick_behavior = behavior;
+    }
+
+    /// Yield the tick scheduled for `scheduled` and work out the next one. `catching_up` is set
+    /// for ticks burst out after a late one, whose lateness was already reported.
+    fn tick(&mut self, scheduled: Duration, fired: Duration, catching_up: bool) -> Tick {
+        let late = fired.saturating_sub(scheduled);
+        let missed = u32::try_from(late.as_nanos() / self.period.as_nanos()).unwrap_or(u32::MAX);
+        let next = match self.missed_tick_behavior {
+            MissedTickBehavior::Burst => scheduled + self.period,
+            MissedTickBehavior::Skip => scheduled + self.period * missed.saturating_add(1),
+            MissedTickBehavior::Delay => fired + self.period,
+        };
+        self.state = if next <= fired {
+            IntervalState::Behind(next, fired)
+        } else {
+            IntervalState::Waiting(next, Timer::at(self.cx.clone(), next))
+        };
+        Tick {
+            scheduled: GameInstant(scheduled),
+            fired: GameInstant(fired),
+            missed: if catching_up { 0 } else { missed },
+        }
+    }
+}
+
+impl<C: Clock> Stream for Interval<C> {
+    type Item = Tick;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            match &mut self.state {
+                IntervalState::Starting(fut) => match fut.poll_unpin(cx) {
+                    Poll::Ready(start) => {
+                        let first = start + self.period;
+                        let timer = Timer::at(self.cx.clone(), first);
+                        self.state = IntervalState::Waiting(first, timer);
+                    }
+                    Poll::Pending => return Poll::Pending,
+                },
+                IntervalState::Waiting(scheduled, timer) => {
+                    let scheduled = *scheduled;
+                    return match timer.poll_unpin(cx) {
+                        Poll::Ready(Ok(fired)) => {
+                            Poll::Ready(Some(self.tick(scheduled, fired, false)))
+                        }
+                        // `Timers` was removed, so this interval will never tick again
+                        Poll::Ready(Err(_)) => Poll::Ready(None),
+                        Poll::Pending => Poll::Pending,
+                    };
+                }
+                IntervalState::Behind(scheduled, fired) => {
+                    let (scheduled, fired) = (*scheduled, *fired);
+                    return Poll::Ready(Some(self.tick(scheduled, fired, true)));
+                }
+            }
+        }
+    }
+}
+
+/// Holds every pending sleep on the clock `C`, ordered by the [`Time::elapsed`] value at which
+/// it expires. [`wake_timers`] pops expired entries each frame, so the cost of a frame is
+/// proportional to the number of timers that fire rather than the number that are waiting.
+///
+/// Dropping a sleep before it fires removes its entry the next time [`run_async_jobs`] runs, so
+/// cancelled tasks don't hold on to their timers:
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_mod_async::{prelude::*, time::Timers, AsyncWork};
+/// # use std::time::Duration;
+/// # let mut app = App::new();
+/// # app.add_plugins((MinimalPlugins, AssetPlugin::default(), AsyncTasksPlugin));
+/// let cx = app.world().resource::<AsyncWork>().create_task_context();
+/// let respawn = cx.sleep(Duration::from_secs(600));
+/// app.update();
+/// assert_eq!(app.world().resource::<Timers>().len(), 1);
+/// drop(respawn);
+/// app.update();
+/// assert!(app.world().resource::<Timers>().is_empty());
+/// ```
+///
+/// [`run_async_jobs`]: crate::run_async_jobs
+#[derive(Resource)]
+pub struct Timers<C: Clock = ()> {
+    pending: BTreeMap<(Duration, WaitId), oneshot::Sender<Duration>>,
+    deadlines: HashMap<WaitId, Duration>,
+    marker: PhantomData<fn() -> C>,
+}
+
+impl<C: Clock> Default for Timers<C> {
+    fn default() -> Self {
+        Self {
+            pending: BTreeMap::new(),
+            deadlines: HashMap::new(),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<C: Clock> Timers<C> {
+    /// Schedule `tx` to be sent the current [`Time::elapsed`] once it reaches `deadline`.
+    pub(crate) fn insert(&mut self, id: WaitId, deadline: Duration, tx: oneshot::Sender<Duration>) {
+        self.pending.insert((deadline, id), tx);
+        self.deadlines.insert(id, deadline);
+    }
+
+    /// Cancel a timer that hasn't fired yet. Returns `false` if there was no such timer.
+    pub(crate) fn remove(&mut self, id: WaitId) -> bool {
+        let Some(deadline) = self.deadlines.remove(&id) else {
+            return false;
+        };
+        self.pending.remove(&(deadline, id));
+        true
+    }
+
+    /// The number of timers that haven't fired yet.
+    pub fn len(&self) -> usize {
+        self.pending.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pending.is_empty()
+    }
+
+    fn expire(&mut self, now: Duration) {
+        while let Some(entry) = self.pending.first_entry() {
+            if entry.key().0 > now {
+                break;
+            }
+            let ((_, id), tx) = entry.remove_entry();
+            self.deadlines.remove(&id);
+            tx.send(now).ok();
+        }
+    }
+}
+
+/// Wakes every task sleeping on the clock `C` whose deadline has passed.
+pub fn wake_timers<C: Clock>(mut timers: ResMut<Timers<C>>, time: Res<Time<C>>) {
+    timers.expire(time.elapsed());
+}
@@ -0,0 +1,79 @@
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::atomic::{AtomicU64, Ordering},
+    task::{Context, Poll},
+};
+
+use bevy_ecs::world::World;
+use futures::FutureExt;
+use tokio::sync::oneshot;
+
+use crate::TaskContext;
+
+/// Identifies an entry a task registered with the world, like a timer or a frame wait, so it can
+/// be cancelled before it resolves. Allocated on the task side, since the entry itself is only
+/// inserted once the task's [`with_world`] job runs.
+///
+/// [`with_world`]: TaskContext::with_world
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub(crate) struct WaitId(u64);
+
+impl WaitId {
+    fn new() -> Self {
+        static NEXT: AtomicU64 = AtomicU64::new(0);
+        Self(NEXT.fetch_add(1, Ordering::Relaxed))
+    }
+}
+
+/// Waits for the world to answer an entry registered under a [`WaitId`]. If this future is
+/// dropped before then, `cancel` is queued so the entry doesn't outlive the task waiting on it.
+pub(crate) struct Wait<T> {
+    cx: TaskContext,
+    id: WaitId,
+    rx: oneshot::Receiver<T>,
+    done: bool,
+    cancel: fn(&mut World, WaitId),
+}
+
+impl<T: Send + 'static> Wait<T> {
+    /// Queue `register` to add an entry the next time [`run_async_jobs`] runs. The entry resolves
+    /// this future by sending on the channel it's given, or fails it by dropping the sender.
+    ///
+    /// [`run_async_jobs`]: crate::run_async_jobs
+    pub(crate) fn new(
+        cx: TaskContext,
+        register: impl FnOnce(&mut World, WaitId, oneshot::Sender<T>) + Send + 'static,
+        cancel: fn(&mut World, WaitId),
+    ) -> Self {
+        let id = WaitId::new();
+        let (tx, rx) = oneshot::channel();
+        cx.with_world(move |world| register(world, id, tx)).detach();
+        Self {
+            cx,
+            id,
+            rx,
+            done: false,
+            cancel,
+        }
+    }
+}
+
+impl<T> Future for Wait<T> {
+    type Output = Result<T, oneshot::error::RecvError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let result = std::task::ready!(self.rx.poll_unpin(cx));
+        self.done = true;
+        Poll::Ready(result)
+    }
+}
+
+impl<T> Drop for Wait<T> {
+    fn drop(&mut self) {
+        if !self.done {
+            let (id, cancel) = (self.id, self.cancel);
+            self.cx.try_with_world(move |world| cancel(world, id));
+        }
+    }
+}
@@ -0,0 +1,336 @@
+#![cfg(feature = "asset")]
+
+use std::{
+    future::Future,
+    path::PathBuf,
+    pin::pin,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use bevy::{
+    asset::{
+        io::{AssetSourceBuilder, AsyncWriteExt, Reader, Writer},
+        saver::{AssetSaver, SavedAsset},
+        AssetLoadError, AssetLoader, LoadContext,
+    },
+    prelude::*,
+    tasks::{block_on, AsyncComputeTaskPool},
+};
+use bevy_mod_async::{
+    async_asset::{AsyncAssetError, LoadDepth, LoadProgress},
+    prelude::*,
+    AsyncWork,
+};
+use futures::{FutureExt, StreamExt};
+use tokio::sync::Notify;
+
+#[derive(Asset, TypePath, Clone, Debug, PartialEq)]
+struct Text(String);
+
+#[derive(Default, TypePath)]
+struct TextLoader;
+
+impl AssetLoader for TextLoader {
+    type Asset = Text;
+    type Settings = ();
+    type Error = std::io::Error;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _: &(),
+        _: &mut LoadContext<'_>,
+    ) -> Result<Text, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        Ok(Text(String::from_utf8_lossy(&bytes).into_owned()))
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["txt"]
+    }
+}
+
+/// Loads text like [`TextLoader`], but only once the test opens the gate.
+#[derive(TypePath)]
+struct GatedTextLoader(Arc<Notify>);
+
+impl AssetLoader for GatedTextLoader {
+    type Asset = Text;
+    type Settings = ();
+    type Error = std::io::Error;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        settings: &(),
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<Text, Self::Error> {
+        self.0.notified().await;
+        TextLoader.load(reader, settings, load_context).await
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["txt"]
+    }
+}
+
+/// Lists text tracks as dependencies. Every playlist points at a missing track, so it loads but
+/// its dependencies fail.
+#[derive(Asset, TypePath)]
+struct Playlist {
+    #[dependency]
+    tracks: Vec<Handle<Text>>,
+}
+
+#[derive(Default, TypePath)]
+struct PlaylistLoader;
+
+impl AssetLoader for PlaylistLoader {
+    type Asset = Playlist;
+    type Settings = ();
+    type Error = std::io::Error;
+
+    async fn load(
+        &self,
+        _: &mut dyn Reader,
+        _: &(),
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<Playlist, Self::Error> {
+        let tracks = vec![load_context.load("missing.txt")];
+        Ok(Playlist { tracks })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["playlist"]
+    }
+}
+
+struct TextSaver;
+
+impl AssetSaver for TextSaver {
+    type Asset = Text;
+    type Settings = ();
+    type OutputLoader = TextLoader;
+    type Error = std::io::Error;
+
+    async fn save(
+        &self,
+        writer: &mut Writer,
+        text: SavedAsset<'_, Text>,
+        _: &(),
+    ) -> Result<(), Self::Error> {
+        writer.write_all(text.0.as_bytes()).await
+    }
+}
+
+/// A directory under the system temp dir that's unique to this test process, removed again when
+/// it's dropped.
+struct TempDir(PathBuf);
+
+impl TempDir {
+    fn new(name: &str) -> Self {
+        let dir = format!("bevy_mod_async_{name}_{}", std::process::id());
+        Self(std::env::temp_dir().join(dir))
+    }
+
+    fn path(&self) -> &str {
+        self.0.to_str().unwrap()
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        std::fs::remove_dir_all(&self.0).ok();
+    }
+}
+
+fn app() -> App {
+    let mut app = App::new();
+    add_plugins(&mut app, AssetPlugin::default());
+    app
+}
+
+fn add_plugins(app: &mut App, assets: AssetPlugin) {
+    app.add_plugins((MinimalPlugins, assets, AsyncTasksPlugin))
+        .init_asset::<Text>()
+        .init_asset::<Playlist>()
+        .init_asset_loader::<TextLoader>()
+        .init_asset_loader::<PlaylistLoader>();
+}
+
+/// Update `app` until `task` finishes, propagating any panic from it.
+fn run<T, F>(mut app: App, task: T)
+where
+    T: FnOnce(TaskContext) -> F,
+    F: Future<Output = ()> + Send + 'static,
+{
+    app.finish();
+    app.cleanup();
+    let cx = app.world().resource::<AsyncWork>().create_task_context();
+    let task = AsyncComputeTaskPool::get().spawn(task(cx));
+    let start = Instant::now();
+    while !task.is_finished() {
+        assert!(start.elapsed() < Duration::from_secs(30), "task timed out");
+        app.update();
+        std::thread::sleep(Duration::from_millis(1));
+    }
+    block_on(task);
+}
+
+#[test]
+fn load_asset_shares_loads() {
+    run(app(), |cx| async move {
+        let (first, second) = futures::join!(
+            cx.load_asset::<Text>("text/hello.txt"),
+            cx.load_asset::<Text>("text/hello.txt"),
+        );
+        assert_eq!(first.unwrap(), second.unwrap());
+    });
+}
+
+#[test]
+fn load_asset_resolves_on_the_frame_it_loads() {
+    let gate = Arc::new(Notify::new());
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, AssetPlugin::default(), AsyncTasksPlugin))
+        .init_asset::<Text>()
+        .register_asset_loader(GatedTextLoader(gate.clone()));
+    app.finish();
+    app.cleanup();
+    let cx = app.world().resource::<AsyncWork>().create_task_context();
+    let mut load = pin!(cx.load_asset::<Text>("text/hello.txt"));
+    // Start the load, then subscribe to it while the gate keeps it from finishing
+    for _ in 0..3 {
+        assert!(load.as_mut().now_or_never().is_none());
+        app.update();
+    }
+    let server = app.world().resource::<AssetServer>();
+    let handle = server.get_handle::<Text>("text/hello.txt").unwrap();
+    gate.notify_one();
+    let start = Instant::now();
+    loop {
+        assert!(start.elapsed() < Duration::from_secs(30), "load timed out");
+        app.update();
+        // The server's load state is what polling would see
+        let server = app.world().resource::<AssetServer>();
+        let loaded = server.is_loaded_with_dependencies(&handle);
+        assert_eq!(load.as_mut().now_or_never().is_some(), loaded);
+        if loaded {
+            break;
+        }
+        std::thread::sleep(Duration::from_millis(1));
+    }
+}
+
+#[test]
+fn load_asset_reports_missing_asset() {
+    run(app(), |cx| async move {
+        let error = cx.load_asset::<Text>("missing.txt").await.unwrap_err();
+        let AsyncAssetError::Load(error) = error else {
+            panic!("expected the asset itself to fail")
+        };
+        assert!(matches!(*error, AssetLoadError::AssetReaderError(_)));
+    });
+}
+
+#[test]
+fn load_asset_with_depth_ignores_failed_dependencies() {
+    run(app(), |cx| async move {
+        let playlist = cx
+            .load_asset_with_depth::<Playlist>("playlists/broken.playlist", LoadDepth::Asset)
+            .await;
+        assert!(playlist.is_ok());
+        let playlist = cx.load_asset::<Playlist>("playlists/broken.playlist").await;
+        assert!(matches!(
+            playlist,
+            Err(AsyncAssetError::DependencyFailed { .. })
+        ));
+    });
+}
+
+#[test]
+fn load_untyped_picks_loader_by_extension() {
+    run(app(), |cx| async move {
+        let handle = cx.load_untyped("text/hello.txt").await.unwrap();
+        assert!(handle.try_typed::<Text>().is_ok());
+    });
+}
+
+#[test]
+fn load_folder_of_filters_by_type() {
+    run(app(), |cx| async move {
+        let texts = cx.load_folder_of::<Text>("text").await.unwrap();
+        assert_eq!(texts.len(), 2);
+    });
+}
+
+#[test]
+fn load_batch_reports_progress() {
+    run(app(), |cx| async move {
+        let batch = cx.load_assets::<Text, _>(["text/hello.txt", "text/world.txt"]);
+        let mut progress = batch.progress();
+        let mut last = LoadProgress::default();
+        while let Some(update) = progress.next().await {
+            last = update;
+        }
+        let expected = LoadProgress {
+            loaded: 2,
+            failed: 0,
+            total: 2,
+        };
+        assert_eq!(last, expected);
+        assert_eq!(batch.await.unwrap().len(), 2);
+    });
+}
+
+#[test]
+fn save_asset_round_trips() {
+    let saves = TempDir::new("save_asset");
+    let mut app = App::new();
+    app.register_asset_source(
+        "saves",
+        AssetSourceBuilder::platform_default(saves.path(), None),
+    );
+    add_plugins(&mut app, AssetPlugin::default());
+    run(app, |cx| async move {
+        let text = Text("generated at runtime".into());
+        let text = cx
+            .with_world(|world| world.resource_mut::<Assets<Text>>().add(text))
+            .await;
+        cx.save_asset(&text, "saves://generated.txt", TextSaver)
+            .await
+            .unwrap();
+        let saved = cx.load_asset_value::<Text>("saves://generated.txt").await;
+        assert_eq!(saved.unwrap().0, "generated at runtime");
+    });
+}
+
+#[cfg(feature = "asset_processor")]
+#[test]
+fn wait_for_processor_waits_until_processed() {
+    use bevy::asset::{
+        processor::{AssetProcessor, ProcessorState},
+        AssetMode,
+    };
+
+    let processed = TempDir::new("processed");
+    let mut app = App::new();
+    let assets = AssetPlugin {
+        mode: AssetMode::Processed,
+        file_path: "assets/text".into(),
+        processed_file_path: processed.path().into(),
+        ..default()
+    };
+    add_plugins(&mut app, assets);
+    run(app, |cx| async move {
+        cx.wait_for_processor().await;
+        let processor = cx
+            .with_world(|world| world.resource::<AssetProcessor>().clone())
+            .await;
+        assert!(processor.get_state().await == ProcessorState::Finished);
+        let hello = cx.load_asset_value::<Text>("hello.txt").await;
+        assert!(hello.is_ok());
+    });
+}